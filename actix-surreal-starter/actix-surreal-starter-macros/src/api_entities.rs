@@ -1,102 +1,185 @@
+/// The internal rules of `#[derive(ApiEntity)]`: the checks of the validators and field markers,
+/// the error types of the fields, the SurrealQL `ASSERT`s and the server fields. Not meant to be
+/// invoked directly.
+#[doc(hidden)]
 #[macro_export]
 macro_rules! api_entities {
-    (
-        validator: $validator_type:ident,
-        error: $validation_error_type:ident,
-        $(
-            $name:ident|$name_error:ident( $db_table_name:literal $( [ $( $path_to_ownership:literal ),* ] )? )
-            {
-                $(
-                    $field:ident: $type:ty $( [ $( $validator:ident $( ( $( $validation_field:ident ),*$(,)? ) )? ),* $(,)? ] )?
-                ),*$(,)*
-            }
-        )*
+    // Field markers are listed among the validators but aren't validated.
+    (@validate $validator_type:ident, $error_type:ident, searchable, $($args:tt)*) => {
+        Ok::<(), $error_type>(())
+    };
+    (@validate $validator_type:ident, $error_type:ident, readonly, $($args:tt)*) => {
+        Ok::<(), $error_type>(())
+    };
+    (@validate $validator_type:ident, $error_type:ident, server_default, $($args:tt)*) => {
+        Ok::<(), $error_type>(())
+    };
+    (@validate $validator_type:ident, $error_type:ident, computed, $($args:tt)*) => {
+        Ok::<(), $error_type>(())
+    };
+    (@validate $validator_type:ident, $error_type:ident, owner, $($args:tt)*) => {
+        Ok::<(), $error_type>(())
+    };
+    // `async(validator)` runs only in `validate_async`.
+    (@validate $validator_type:ident, $error_type:ident, async, $($args:tt)*) => {
+        Ok::<(), $error_type>(())
+    };
+    (@validate $validator_type:ident, $error_type:ident, $validator:ident, $args:tt, [$($field:tt)+]) => {
+        $validator_type::$validator($args).map_err(|e| {
+            actix_surreal_starter::validation::ComparedFields::compared_to(e, $crate::api_entities!(@compared [] $($field)+))
+        })
+    };
+    (@validate $validator_type:ident, $error_type:ident, $validator:ident, $args:tt $(, [])?) => {
+        $validator_type::$validator($args)
+    };
+    // Arguments of validators are other fields of the entity, or values, e.g. `one_of(["a", "b"])`.
+    (@arg $entity:ident, true) => {
+        true
+    };
+    (@arg $entity:ident, false) => {
+        false
+    };
+    (@arg $entity:ident, $field:ident) => {
+        &$entity.$field
+    };
+    (@arg $entity:ident, [$($value:tt)*]) => {
+        &[$($value)*]
+    };
+    (@arg $entity:ident, $value:tt) => {
+        $value
+    };
+    // The names of the fields among the arguments.
+    (@compared [$($fields:expr,)*]) => {
+        &[$($fields),*]
+    };
+    (@compared [$($fields:expr,)*] true $($rest:tt)*) => {
+        $crate::api_entities!(@compared [$($fields,)*] $($rest)*)
+    };
+    (@compared [$($fields:expr,)*] false $($rest:tt)*) => {
+        $crate::api_entities!(@compared [$($fields,)*] $($rest)*)
+    };
+    (@compared [$($fields:expr,)*] $field:ident $($rest:tt)*) => {
+        $crate::api_entities!(@compared [$($fields,)* stringify!($field),] $($rest)*)
+    };
+    (@compared [$($fields:expr,)*] $value:tt $($rest:tt)*) => {
+        $crate::api_entities!(@compared [$($fields,)*] $($rest)*)
+    };
+    // The checks of a field by one of its validators. `each([...])` applies validators to every
+    // item of a collection and `nested` validates an entity held by the field, embedding its
+    // errors in the errors of the field, so it takes no other validators.
+    (@check [$errors:ident, $erronous:ident, $entity:ident, $field:ident] $validator_type:ident, $error_type:ident, each,
+        [[$( $inner:ident $( ( $( $inner_arg:tt ),* $(,)? ) )? ),* $(,)?]]
     ) => {
-
-        pub static PATHS:phf::Map<&'static str, &'static [&'static str]> = phf::phf_map! {
-            $($db_table_name => &[$($($path_to_ownership),*)?] as &[&str],)*
-        };
-
-        pub fn configure_endpoints(cfg: &mut actix_web::web::ServiceConfig) {
-            cfg
+        for (index, item) in $entity.$field.iter().enumerate() {
             $(
-            .route(concat!("/api/", $db_table_name, "/all"), actix_web::web::get().to(
-                |user_id: actix_surreal_starter::UserId| async move {
-                    actix_surreal_starter::crud_ops::select_all::<$name>(user_id.0, $name::query_builder()).await.map(actix_web::web::Json)
-                }
-            ))
-            .route(concat!("/api/", $db_table_name), actix_web::web::get().to(
-                |id: actix_web::web::Json<::surrealdb::RecordId>, user_id: actix_surreal_starter::UserId| async move {
-                    actix_surreal_starter::crud_ops::select::<$name>(id.0, user_id.0, $name::query_builder()).await.map(actix_web::web::Json)
-                }
-            ))
-            .route(concat!("/api/", $db_table_name), actix_web::web::post().to(
-                |entity: actix_web::web::Json<$name>, user_id: actix_surreal_starter::UserId| async move {
-                    Ok::<_, ::actix_surreal_starter::crud_ops::CrudError>(::actix_web::HttpResponse::Ok().json(actix_surreal_starter::crud_ops::insert(entity.0, user_id.0,$name::query_builder()).await?))
-                }
-            ))
-            .route(concat!("/api/", $db_table_name), actix_web::web::put().to(
-                |entity: actix_web::web::Json<actix_surreal_starter::api::WithId<serde_json::Value>>, user_id: actix_surreal_starter::UserId| async move {
-                    actix_surreal_starter::crud_ops::update(entity.0.id, entity.0.data, user_id.0, $name::query_builder()).await
-                }
-            ))
-            .route(concat!("/api/", $db_table_name), actix_web::web::delete().to(
-                |id: actix_web::web::Json<surrealdb::RecordId>, user_id: actix_surreal_starter::UserId| async move {
-                    actix_surreal_starter::crud_ops::delete(id.0, user_id.0, $name::query_builder()).await
-                }
-            ))
-            )*;
+            if let Err(e) = $crate::api_entities!(@validate $validator_type, $error_type, $inner, (item $($(, $crate::api_entities!(@arg $entity, $inner_arg))*)?), [$($( $inner_arg )*)?]) {
+                $errors.push(actix_surreal_starter::validation::ItemError::at_index(e, index).into());
+                $erronous = true;
+            }
+            )*
         }
-
-        $(
-        #[derive(std::fmt::Debug, serde::Deserialize, serde::Serialize, Clone)]
-        pub struct $name {
-            $(pub $field: $type),*
+    };
+    (@check [$errors:ident, $erronous:ident, $entity:ident, $field:ident] $validator_type:ident, $error_type:ident, nested, []) => {
+        if let Err(e) = actix_surreal_starter::entity::ApiEntity::validate(&$entity.$field) {
+            $errors = Some(e);
+            $erronous = true;
         }
-        #[derive(std::fmt::Debug, serde::Deserialize, serde::Serialize, Clone)]
-        pub struct $name_error {
-            $(pub $field: Vec<$validation_error_type>),*
+    };
+    (@check [$errors:ident, $erronous:ident, $entity:ident, $field:ident] $validator_type:ident, $error_type:ident, $validator:ident, [$($arg:tt),*]) => {
+        if let Err(e) = $crate::api_entities!(@validate $validator_type, $error_type, $validator, (&$entity.$field $(, $crate::api_entities!(@arg $entity, $arg))*), [$($arg)*]) {
+            $errors.push(e.into());
+            $erronous = true;
         }
-
-        impl $name {
-            fn paths() -> &'static [&'static str] {
-                PATHS.get(Self::table_name()).unwrap()
-            }
-            pub fn table_name() -> &'static str {
-                $db_table_name
-            }
-            pub fn request_address() -> &'static str {
-                concat!("/api/", $db_table_name)
-            }
-            fn query_builder() -> actix_surreal_starter::query_builder::QueryBuilder {
-                actix_surreal_starter::query_builder::QueryBuilder {
-                    paths: Self::paths(),
-                    table_name: Self::table_name(),
-                    fkey_path_map: None, //TODO: nah oh it can't be None it's just a placeholder
-                }
-            }
-            pub fn validate(&self) -> Result<(), $name_error> {
-                let mut erronous = false;
-                let mut result = $name_error {
-                    $(
-                    $field: {
-                        let mut errors: Vec<$validation_error_type> = Vec::new();
-                        $($(
-                            if let Err(e) = $validator_type::$validator((&self.$field $($(, &self.$validation_field)* )?)) {
-                                errors.push(e.into());
-                                erronous = true;
-                            }
-                        )*)?
-                        errors
-                    },
-                    )*
-                };
-                match erronous {
-                    true => Err(result),
-                    false => Ok(()),
-                }
-            }
+    };
+    (@check_async [$result:ident, $erronous:ident, $entity:ident, $field:ident, $context:ident] $validator_type:ident, $error_type:ident, async, [$validator:ident]) => {
+        if let Err(e) = $validator_type::$validator($context, &$entity.$field).await {
+            $result.$field.push(e.into());
+            $erronous = true;
+        }
+    };
+    (@check_async [$result:ident, $erronous:ident, $entity:ident, $field:ident, $context:ident] $validator_type:ident, $error_type:ident, $validator:ident, $args:tt) => {};
+    (@error_type $error_type:ident, $type:ty, []) => {
+        Vec<$error_type>
+    };
+    (@error_type $error_type:ident, $type:ty, [nested $($rest:ident)*]) => {
+        Option<<$type as actix_surreal_starter::entity::ApiEntity>::Error>
+    };
+    (@error_type $error_type:ident, $type:ty, [$validator:ident $($rest:ident)*]) => {
+        $crate::api_entities!(@error_type $error_type, $type, [$($rest)*])
+    };
+    (@nested_error $type:ty, []) => {
+        None
+    };
+    (@nested_error $type:ty, [nested $($rest:ident)*]) => {
+        Some(<$type as actix_surreal_starter::entity::ApiEntity>::openapi().error_name)
+    };
+    (@nested_error $type:ty, [$validator:ident $($rest:ident)*]) => {
+        $crate::api_entities!(@nested_error $type, [$($rest)*])
+    };
+    (@validate_entity_async $validator_type:ident, $error_type:ident, async, [$validator:ident], $entity:ident, $context:ident) => {
+        $validator_type::$validator($context, $entity).await
+    };
+    (@validate_entity_async $validator_type:ident, $error_type:ident, $validator:ident, $args:tt, $entity:ident, $context:ident) => {
+        Ok::<(), $error_type>(())
+    };
+    (@assert not_empty) => {
+        Some("string::len(string::trim($value)) > 0")
+    };
+    (@assert gt_zero) => {
+        Some("$value > 0")
+    };
+    (@assert ge_zero) => {
+        Some("$value >= 0")
+    };
+    (@assert lt_zero) => {
+        Some("$value < 0")
+    };
+    (@assert le_zero) => {
+        Some("$value <= 0")
+    };
+    (@assert eq_zero) => {
+        Some("$value = 0")
+    };
+    (@assert ne_zero) => {
+        Some("$value != 0")
+    };
+    (@assert $validator:ident) => {
+        None
+    };
+    (@searchable [$($fields:expr,)*]) => {
+        &[$($fields),*]
+    };
+    (@searchable [$($fields:expr,)*] $field:ident searchable $($rest:ident)*) => {
+        $crate::api_entities!(@searchable [$($fields,)* stringify!($field),] $($rest)*)
+    };
+    (@searchable [$($fields:expr,)*] $field:ident $validator:ident $($rest:ident)*) => {
+        $crate::api_entities!(@searchable [$($fields,)*] $($rest)*)
+    };
+    (@server_fields [$($fields:expr,)*]) => {
+        &[$($fields),*]
+    };
+    (@server_fields [$($fields:expr,)*] $field:ident readonly () $($rest:tt)*) => {
+        $crate::api_entities!(@server_fields [$($fields,)* $crate::api_entities!(@server_field $field Readonly),] $($rest)*)
+    };
+    (@server_fields [$($fields:expr,)*] $field:ident owner () $($rest:tt)*) => {
+        $crate::api_entities!(@server_fields [$($fields,)* $crate::api_entities!(@server_field $field Owner),] $($rest)*)
+    };
+    (@server_fields [$($fields:expr,)*] $field:ident server_default (now) $($rest:tt)*) => {
+        $crate::api_entities!(@server_fields [$($fields,)* $crate::api_entities!(@server_field $field Default("time::now()")),] $($rest)*)
+    };
+    (@server_fields [$($fields:expr,)*] $field:ident server_default ($value:literal) $($rest:tt)*) => {
+        $crate::api_entities!(@server_fields [$($fields,)* $crate::api_entities!(@server_field $field Default(stringify!($value))),] $($rest)*)
+    };
+    (@server_fields [$($fields:expr,)*] $field:ident computed ($expression:literal) $($rest:tt)*) => {
+        $crate::api_entities!(@server_fields [$($fields,)* $crate::api_entities!(@server_field $field Computed($expression)),] $($rest)*)
+    };
+    (@server_fields [$($fields:expr,)*] $field:ident $validator:ident $args:tt $($rest:tt)*) => {
+        $crate::api_entities!(@server_fields [$($fields,)*] $($rest)*)
+    };
+    (@server_field $field:ident $($value:tt)*) => {
+        actix_surreal_starter::query_builder::ServerField {
+            name: stringify!($field),
+            value: actix_surreal_starter::query_builder::ServerValue::$($value)*,
         }
-        )*
     };
 }
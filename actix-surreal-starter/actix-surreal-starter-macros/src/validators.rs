@@ -7,27 +7,73 @@ macro_rules! impl_validators {
     {
         (
             trait_name: $vis:vis $validation_struct_name:ident,
+            $(names: $names_vis:vis $names:ident,)?
             parameter_name: $value_name:ident,
+            $(context_name: $context_name:ident,)?
             error: $validation_error_type:ident $(,)?
         )
         {
-            $(
-                $fn_name:ident$(<$( $type_ident:ident $(: $( $generic_type:path )|* )? ),+>)?($($value_type:ty),*) {
-                    $($logic:expr$(=>$result:ident $( ($result_expr:expr) )?)?)*
-                }
-            )*
+            $($validators:tt)*
         }
     } => {
          $vis trait $validation_struct_name {
-            $(
-            fn $fn_name$(<$( $type_ident$(: $( $generic_type + )+ std::any::Any ,)* )?>)?($value_name:impl_validators!(@parse_param $($value_type)*)) -> Result<(), $validation_error_type> {
+            impl_validators!(@validators [$value_name, $validation_error_type, $($context_name)?] $($validators)*);
+        }
+        impl_validators!(@names_const [$($names_vis $names)?] $($validators)*);
+    };
+    // `names` lists the validators of the trait, e.g. for the generated clients to tell which
+    // of the validators of an entity they can run.
+    (@names_const [] $($validators:tt)*) => {};
+    (@names_const [$names_vis:vis $names:ident] $($validators:tt)*) => {
+        /// The names of the validators of the trait declared along with it.
+        $names_vis const $names: &[&str] = impl_validators!(@names [] $($validators)*);
+    };
+    (@names [$($names:expr,)*]) => {
+        &[$($names),*]
+    };
+    (@names [$($names:expr,)*] async $fn_name:ident($($params:tt)*) $body:tt $($rest:tt)*) => {
+        impl_validators!(@names [$($names,)* stringify!($fn_name),] $($rest)*)
+    };
+    (@names [$($names:expr,)*]
+        $fn_name:ident$(<$( $type_ident:ident $(: $( $generic_type:path )|* )? ),+>)?($($params:tt)*) $body:tt
+        $($rest:tt)*
+    ) => {
+        impl_validators!(@names [$($names,)* stringify!($fn_name),] $($rest)*)
+    };
+    (@validators [$value_name:ident, $validation_error_type:ident, $($context_name:ident)?]) => {};
+    // Async validators also get the `ValidationContext`, named by `context_name`.
+    (@validators [$value_name:ident, $validation_error_type:ident, $context_name:ident]
+        async $fn_name:ident($($value_type:ty),*) {
+            $($logic:expr$(=>$result:ident $( ($result_expr:expr) )?)?)*
+        }
+        $($rest:tt)*
+    ) => {
+        fn $fn_name(
+            $context_name: actix_surreal_starter::validation::ValidationContext<'_>,
+            $value_name: impl_validators!(@parse_param $($value_type)*),
+        ) -> impl std::future::Future<Output = Result<(), $validation_error_type>> {
+            async move {
                 $(
                 impl_validators!(@parse_logic $logic $(, @error $result $( $result_expr )* => $validation_error_type)?);
                 )*
                 Ok(())
             }
+        }
+        impl_validators!(@validators [$value_name, $validation_error_type, $context_name] $($rest)*);
+    };
+    (@validators [$value_name:ident, $validation_error_type:ident, $($context_name:ident)?]
+        $fn_name:ident$(<$( $type_ident:ident $(: $( $generic_type:path )|* )? ),+>)?($($value_type:ty),*) {
+            $($logic:expr$(=>$result:ident $( ($result_expr:expr) )?)?)*
+        }
+        $($rest:tt)*
+    ) => {
+        fn $fn_name$(<$( $type_ident$(: $( $generic_type + )+ std::any::Any ,)* )?>)?($value_name:impl_validators!(@parse_param $($value_type)*)) -> Result<(), $validation_error_type> {
+            $(
+            impl_validators!(@parse_logic $logic $(, @error $result $( $result_expr )* => $validation_error_type)?);
             )*
+            Ok(())
         }
+        impl_validators!(@validators [$value_name, $validation_error_type, $($context_name)?] $($rest)*);
     };
     (@parse_logic $logic:expr, @error $result:ident $( $result_expr:expr )* => $validation_error_type:ident) => {
         if ($logic) {
@@ -0,0 +1,578 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote, ToTokens};
+use syn::ext::IdentExt;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{
+    parenthesized, token, Attribute, Data, DeriveInput, Error, Expr, ExprArray, ExprLit, Fields,
+    Ident, Lit, LitStr, Token,
+};
+
+/// `key` or `key = value` of `#[api(...)]`.
+struct ApiArg {
+    key: Ident,
+    value: Option<Expr>,
+}
+
+impl Parse for ApiArg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let key = input.parse()?;
+        let value = match input.peek(Token![=]) {
+            true => {
+                input.parse::<Token![=]>()?;
+                Some(input.parse()?)
+            }
+            false => None,
+        };
+        Ok(Self { key, value })
+    }
+}
+
+/// The arguments of `#[api(...)]` with their defaults.
+struct ApiArgs {
+    table: LitStr,
+    owner_paths: Vec<LitStr>,
+    error: Ident,
+    validator: Ident,
+    validation_error: Ident,
+    /// The remaining arguments, set on `EntityOptions`.
+    options: Vec<(Ident, TokenStream)>,
+}
+
+impl ApiArgs {
+    fn from_input(input: &DeriveInput) -> syn::Result<Self> {
+        let mut table = None;
+        let mut owner_paths = Vec::new();
+        let mut error = format_ident!("{}Error", input.ident);
+        let mut validator = format_ident!("Validator");
+        let mut validation_error = format_ident!("ApiValidationError");
+        let mut options = Vec::new();
+        for attr in input.attrs.iter().filter(|attr| attr.path.is_ident("api")) {
+            let args = attr.parse_args_with(Punctuated::<ApiArg, Token![,]>::parse_terminated)?;
+            for ApiArg { key, value } in args {
+                match (key.to_string().as_str(), value) {
+                    ("table", Some(value)) => table = Some(syn::parse2(value.into_token_stream())?),
+                    ("owner_paths", Some(value)) => {
+                        let paths: ExprArray = syn::parse2(value.into_token_stream())?;
+                        owner_paths = paths
+                            .elems
+                            .into_iter()
+                            .map(|path| syn::parse2(path.into_token_stream()))
+                            .collect::<syn::Result<_>>()?;
+                    }
+                    ("error", Some(value)) => error = syn::parse2(value.into_token_stream())?,
+                    ("validator", Some(value)) => {
+                        validator = syn::parse2(value.into_token_stream())?
+                    }
+                    ("validation_error", Some(value)) => {
+                        validation_error = syn::parse2(value.into_token_stream())?
+                    }
+                    (
+                        "table" | "owner_paths" | "error" | "validator" | "validation_error",
+                        None,
+                    ) => {
+                        return Err(Error::new(key.span(), format!("`{}` needs a value", key)));
+                    }
+                    (_, Some(value)) => options.push((key, value.into_token_stream())),
+                    (_, None) => options.push((key, quote!(true))),
+                }
+            }
+        }
+        let table = table
+            .ok_or_else(|| Error::new(Span::call_site(), "missing `#[api(table = \"...\")]`"))?;
+        Ok(Self {
+            table,
+            owner_paths,
+            error,
+            validator,
+            validation_error,
+            options,
+        })
+    }
+}
+
+/// An argument of a validator: another field of the entity, or a value.
+enum ValidatorArg {
+    Field(Ident),
+    Value(Box<Expr>),
+}
+
+impl Parse for ValidatorArg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(match input.parse()? {
+            Expr::Path(path) if path.qself.is_none() && path.path.get_ident().is_some() => {
+                ValidatorArg::Field(path.path.get_ident().cloned().unwrap())
+            }
+            value => ValidatorArg::Value(Box::new(value)),
+        })
+    }
+}
+
+/// An item of `#[validate(...)]`, e.g. `not_empty`, `v1_gt_v2(end_date)` or `async(record_exists)`.
+struct FieldValidator {
+    name: Ident,
+    args: Vec<ValidatorArg>,
+}
+
+impl Parse for FieldValidator {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = Ident::parse_any(input)?;
+        let args = match input.peek(token::Paren) {
+            true => {
+                let content;
+                parenthesized!(content in input);
+                Punctuated::<ValidatorArg, Token![,]>::parse_terminated(&content)?
+                    .into_iter()
+                    .collect()
+            }
+            false => Vec::new(),
+        };
+        check_patterns(
+            &name,
+            args.iter().filter_map(|arg| match arg {
+                ValidatorArg::Value(value) => Some(&**value),
+                ValidatorArg::Field(_) => None,
+            }),
+        )?;
+        Ok(Self { name, args })
+    }
+}
+
+/// Rejects the invalid patterns of `matches("...")`, also among the validators of `each(...)`,
+/// which would otherwise fail every value.
+fn check_patterns<'a>(name: &Ident, mut args: impl Iterator<Item = &'a Expr>) -> syn::Result<()> {
+    if name == "each" {
+        for arg in args {
+            if let Expr::Call(call) = arg {
+                if let Expr::Path(path) = &*call.func {
+                    if let Some(name) = path.path.get_ident() {
+                        check_patterns(name, call.args.iter())?;
+                    }
+                }
+            }
+        }
+        return Ok(());
+    }
+    if name != "matches" {
+        return Ok(());
+    }
+    if let Some(Expr::Lit(ExprLit {
+        lit: Lit::Str(pattern),
+        ..
+    })) = args.next()
+    {
+        if let Err(e) = regex::Regex::new(&pattern.value()) {
+            return Err(Error::new(
+                pattern.span(),
+                format!("invalid pattern: {}", e),
+            ));
+        }
+    }
+    Ok(())
+}
+
+struct EntityField {
+    name: Ident,
+    ty: syn::Type,
+    validators: Vec<FieldValidator>,
+}
+
+/// The validators of the `#[validate(...)]` attributes of a field, or of the struct for the
+/// validators of the whole entity.
+fn validators(attrs: &[Attribute]) -> syn::Result<Vec<FieldValidator>> {
+    let mut validators = Vec::new();
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("validate")) {
+        let list =
+            attr.parse_args_with(Punctuated::<FieldValidator, Token![,]>::parse_terminated)?;
+        validators.extend(list);
+    }
+    Ok(validators)
+}
+
+impl FieldValidator {
+    /// The validator as written, e.g. `v1_gt_v2(end_date)`, for the generated clients.
+    fn declared(&self) -> LitStr {
+        let mut declared = self.name.to_string();
+        if !self.args.is_empty() {
+            let args: Vec<String> = self.args.iter().map(|arg| arg.raw().to_string()).collect();
+            declared += &format!("({})", args.join(", "));
+        }
+        LitStr::new(&declared, self.name.span())
+    }
+
+    /// The arguments as the `@check` rule of `api_entities!` takes them, where `each` lists its validators in brackets.
+    fn check_args(&self) -> TokenStream {
+        match self.name == "each" {
+            true => {
+                let inner = self.args.iter().map(ValidatorArg::raw);
+                quote!([[#(#inner),*]])
+            }
+            false => {
+                let args = self.args.iter().map(ValidatorArg::tree);
+                quote!([#(#args),*])
+            }
+        }
+    }
+}
+
+impl ValidatorArg {
+    /// The argument passed to the validator function.
+    fn value(&self) -> TokenStream {
+        match self {
+            ValidatorArg::Field(field) => quote!(&self.#field),
+            ValidatorArg::Value(value) if matches!(**value, Expr::Array(_)) => quote!(&#value),
+            ValidatorArg::Value(value) => quote!(#value),
+        }
+    }
+
+    /// The argument as written, for the `@server_fields` rule of `api_entities!`.
+    fn raw(&self) -> TokenStream {
+        match self {
+            ValidatorArg::Field(field) => quote!(#field),
+            ValidatorArg::Value(value) => quote!(#value),
+        }
+    }
+
+    /// The argument as a single token tree, which the internal rules of `api_entities!` take.
+    fn tree(&self) -> TokenStream {
+        match self {
+            ValidatorArg::Value(value) if !matches!(**value, Expr::Lit(_) | Expr::Array(_)) => {
+                quote!((#value))
+            }
+            arg => arg.raw(),
+        }
+    }
+}
+
+/// Generates the items of an API entity, leaving the validators and field markers to the
+/// internal rules of `api_entities!`.
+pub fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
+    if !input.generics.params.is_empty() {
+        return Err(Error::new(
+            input.generics.span(),
+            "API entities can't be generic",
+        ));
+    }
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new(
+            input.ident.span(),
+            "only structs can derive `ApiEntity`",
+        ));
+    };
+    let Fields::Named(named_fields) = &data.fields else {
+        return Err(Error::new(
+            input.ident.span(),
+            "API entities need named fields",
+        ));
+    };
+    let args = ApiArgs::from_input(&input)?;
+    let mut fields = Vec::new();
+    let mut has_version = false;
+    for field in &named_fields.named {
+        let name = field.ident.clone().unwrap();
+        // The version of versioned tables is managed by the queries.
+        if name == "version" {
+            has_version = true;
+            continue;
+        }
+        fields.push(EntityField {
+            name,
+            ty: field.ty.clone(),
+            validators: validators(&field.attrs)?,
+        });
+    }
+    let versioned = args.options.iter().any(|(key, _)| key == "versioned");
+    if versioned && !has_version {
+        return Err(Error::new(
+            input.ident.span(),
+            "versioned entities need a `version: Option<u64>` field, \
+            with `#[serde(default, skip_serializing_if = \"Option::is_none\")]`",
+        ));
+    }
+
+    let name = &input.ident;
+    let vis = &input.vis;
+    let ApiArgs {
+        table,
+        owner_paths,
+        error,
+        validator,
+        validation_error,
+        options,
+    } = &args;
+    let field_names: Vec<&Ident> = fields.iter().map(|field| &field.name).collect();
+    let field_types: Vec<&syn::Type> = fields.iter().map(|field| &field.ty).collect();
+    let option_keys = options.iter().map(|(key, _)| key);
+    let option_values = options.iter().map(|(_, value)| value);
+    let markers = fields.iter().flat_map(|field| {
+        let name = &field.name;
+        field.validators.iter().map(move |validator| {
+            let validator_name = &validator.name;
+            let raw = validator.args.iter().map(ValidatorArg::raw);
+            quote!(#name #validator_name (#(#raw)*))
+        })
+    });
+    let searchable = fields.iter().flat_map(|field| {
+        let name = &field.name;
+        field.validators.iter().map(move |validator| {
+            let validator_name = &validator.name;
+            quote!(#name #validator_name)
+        })
+    });
+    let asserts = fields.iter().map(|field| {
+        let validator_names = field.validators.iter().map(|validator| &validator.name);
+        quote! {
+            {
+                let asserts: Vec<Option<&'static str>> = vec![#(actix_surreal_starter::api_entities!(@assert #validator_names),)*];
+                asserts.into_iter().flatten().collect()
+            }
+        }
+    });
+    let error_types: Vec<TokenStream> = fields
+        .iter()
+        .map(|field| {
+            let ty = &field.ty;
+            let validator_names = field.validators.iter().map(|validator| &validator.name);
+            quote!(actix_surreal_starter::api_entities!(@error_type #validation_error, #ty, [#(#validator_names)*]))
+        })
+        .collect();
+    let nested_errors = fields.iter().map(|field| {
+        let ty = &field.ty;
+        let validator_names = field.validators.iter().map(|validator| &validator.name);
+        quote!(actix_surreal_starter::api_entities!(@nested_error #ty, [#(#validator_names)*]))
+    });
+    let validations = fields.iter().zip(&error_types).map(|(field, error_type)| {
+        let name = &field.name;
+        let checks = field.validators.iter().map(|field_validator| {
+            let validator_name = &field_validator.name;
+            let args = field_validator.check_args();
+            quote! {
+                actix_surreal_starter::api_entities!(@check [errors, erronous, self, #name] #validator, #validation_error, #validator_name, #args);
+            }
+        });
+        quote! {
+            #name: {
+                let mut errors: #error_type = Default::default();
+                #(#checks)*
+                errors
+            },
+        }
+    });
+    let async_validations = fields.iter().flat_map(|field| {
+        let name = &field.name;
+        field.validators.iter().map(move |field_validator| {
+            let validator_name = &field_validator.name;
+            let args = field_validator.check_args();
+            quote! {
+                actix_surreal_starter::api_entities!(@check_async [result, erronous, self, #name, context] #validator, #validation_error, #validator_name, #args);
+            }
+        })
+    });
+    // Fields the server fills in get a placeholder, so that the clients may leave them out.
+    let placeholders = fields
+        .iter()
+        .filter(|field| {
+            field.validators.iter().any(|validator| {
+                ["readonly", "server_default", "computed"]
+                    .iter()
+                    .any(|marker| validator.name == marker)
+            })
+        })
+        .map(|field| {
+            let key = field.name.to_string();
+            let key = LitStr::new(key.trim_start_matches("r#"), field.name.span());
+            let ty = &field.ty;
+            quote! {
+                placeholders.insert(
+                    #key.to_string(),
+                    serde_json::to_value(<#ty as Default>::default()).unwrap_or_default(),
+                );
+            }
+        });
+    let entity_validators = validators(&input.attrs)?;
+    // Validators of the whole entity get it, or the listed fields.
+    let entity_validations = entity_validators.iter().map(|entity_validator| {
+        let validator_name = &entity_validator.name;
+        let args = match entity_validator.args.is_empty() {
+            true => quote!((self)),
+            false => {
+                let values = entity_validator.args.iter().map(ValidatorArg::value);
+                quote!((#(#values),*))
+            }
+        };
+        quote! {
+            if let Err(e) = actix_surreal_starter::api_entities!(@validate #validator, #validation_error, #validator_name, #args) {
+                errors.push(e.into());
+                erronous = true;
+            }
+        }
+    });
+
+    let field_validator_names = fields.iter().map(|field| {
+        let names = field.validators.iter().map(FieldValidator::declared);
+        quote!(#(#names),*)
+    });
+    let entity_validator_names = entity_validators.iter().map(FieldValidator::declared);
+
+    let async_entity_validations = entity_validators.iter().map(|entity_validator| {
+        let validator_name = &entity_validator.name;
+        let raw = entity_validator.args.iter().map(ValidatorArg::raw);
+        quote! {
+            if let Err(e) = actix_surreal_starter::api_entities!(@validate_entity_async #validator, #validation_error, #validator_name, [#(#raw)*], self, context) {
+                result._entity.push(e.into());
+                erronous = true;
+            }
+        }
+    });
+
+    Ok(quote! {
+        #[derive(std::fmt::Debug, serde::Deserialize, serde::Serialize, Clone)]
+        #vis struct #error {
+            #(pub #field_names: #error_types,)*
+            /// Errors of the validators of the whole entity.
+            pub _entity: Vec<#validation_error>,
+        }
+
+        impl actix_surreal_starter::openapi::OpenApiType for #name {
+            fn openapi_schema() -> serde_json::Value {
+                actix_surreal_starter::openapi::schema_ref(stringify!(#name))
+            }
+        }
+        impl actix_surreal_starter::openapi::OpenApiType for #error {
+            fn openapi_schema() -> serde_json::Value {
+                actix_surreal_starter::openapi::schema_ref(stringify!(#error))
+            }
+        }
+        impl actix_surreal_starter::schema::SurrealType for #name {
+            fn surreal_type() -> String {
+                "object".to_string()
+            }
+        }
+
+        impl #name {
+            pub fn table_name() -> &'static str {
+                #table
+            }
+            pub fn request_address() -> &'static str {
+                concat!("/api/", #table)
+            }
+            pub fn query_builder() -> &'static actix_surreal_starter::query_builder::QueryBuilder {
+                const OPTIONS: actix_surreal_starter::query_builder::EntityOptions =
+                    actix_surreal_starter::query_builder::EntityOptions {
+                        searchable: actix_surreal_starter::api_entities!(@searchable [] #(#searchable)*),
+                        server_fields: actix_surreal_starter::api_entities!(@server_fields [] #(#markers)*),
+                        #(#option_keys: #option_values,)*
+                        ..actix_surreal_starter::query_builder::EntityOptions::DEFAULT
+                    };
+                static QUERY_BUILDER: std::sync::LazyLock<actix_surreal_starter::query_builder::QueryBuilder> =
+                    std::sync::LazyLock::new(|| actix_surreal_starter::query_builder::QueryBuilder::new(
+                        #table,
+                        &[#(#owner_paths),*],
+                        None,
+                        OPTIONS,
+                    ));
+                &QUERY_BUILDER
+            }
+            /// `DEFINE` statements for the table, with field types derived from the Rust types
+            /// and `ASSERT`s from the validators that have a SurrealQL equivalent.
+            pub fn schema() -> String {
+                actix_surreal_starter::schema::entity_schema(#name::query_builder(), &[
+                    #(
+                    actix_surreal_starter::schema::FieldSchema {
+                        name: stringify!(#field_names),
+                        surreal_type: <#field_types as actix_surreal_starter::schema::SurrealType>::surreal_type(),
+                        asserts: #asserts,
+                    },
+                    )*
+                ])
+            }
+            pub fn openapi() -> actix_surreal_starter::openapi::EntityDoc {
+                actix_surreal_starter::openapi::EntityDoc {
+                    name: stringify!(#name),
+                    error_name: stringify!(#error),
+                    query_builder: #name::query_builder(),
+                    fields: vec![
+                        #(
+                        actix_surreal_starter::openapi::FieldDoc {
+                            name: stringify!(#field_names),
+                            rust_type: stringify!(#field_types),
+                            schema: <#field_types as actix_surreal_starter::openapi::OpenApiType>::openapi_schema(),
+                            required: <#field_types as actix_surreal_starter::openapi::OpenApiType>::required(),
+                            validators: &[#field_validator_names],
+                            nested_error: #nested_errors,
+                        },
+                        )*
+                    ],
+                    validators: &[#(#entity_validator_names),*],
+                }
+            }
+            pub fn validate(&self) -> Result<(), #error> {
+                let mut erronous = false;
+                let result = #error {
+                    #(#validations)*
+                    _entity: {
+                        let mut errors: Vec<#validation_error> = Vec::new();
+                        #(#entity_validations)*
+                        errors
+                    },
+                };
+                match erronous {
+                    true => Err(result),
+                    false => Ok(()),
+                }
+            }
+            /// [`Self::validate`] along with the `async` validators, checking the entity against the database.
+            pub async fn validate_async(
+                &self,
+                context: actix_surreal_starter::validation::ValidationContext<'_>,
+            ) -> Result<(), #error> {
+                let (mut result, mut erronous) = match self.validate() {
+                    Ok(()) => (#error { #(#field_names: Default::default(),)* _entity: Vec::new() }, false),
+                    Err(result) => (result, true),
+                };
+                #(#async_validations)*
+                #(#async_entity_validations)*
+                match erronous {
+                    true => Err(result),
+                    false => Ok(()),
+                }
+            }
+        }
+
+        impl actix_surreal_starter::entity::ApiEntity for #name {
+            type Error = #error;
+
+            fn table_name() -> &'static str {
+                #name::table_name()
+            }
+            fn request_address() -> &'static str {
+                #name::request_address()
+            }
+            fn query_builder() -> &'static actix_surreal_starter::query_builder::QueryBuilder {
+                #name::query_builder()
+            }
+            fn schema() -> String {
+                #name::schema()
+            }
+            fn openapi() -> actix_surreal_starter::openapi::EntityDoc {
+                #name::openapi()
+            }
+            fn server_placeholders() -> serde_json::Map<String, serde_json::Value> {
+                #[allow(unused_mut)]
+                let mut placeholders = serde_json::Map::new();
+                #(#placeholders)*
+                placeholders
+            }
+            fn validate(&self) -> Result<(), #error> {
+                #name::validate(self)
+            }
+            fn validate_async(
+                &self,
+                context: actix_surreal_starter::validation::ValidationContext<'_>,
+            ) -> impl std::future::Future<Output = Result<(), #error>> {
+                #name::validate_async(self, context)
+            }
+        }
+    })
+}
@@ -1,6 +1,8 @@
+mod api_entity;
+
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{parse_macro_input, ItemEnum, Meta, Path};
+use syn::{parse_macro_input, DeriveInput, ItemEnum, Meta, Path};
 
 #[proc_macro_attribute]
 pub fn error_type(attr: TokenStream, item: TokenStream) -> TokenStream {
@@ -42,3 +44,35 @@ pub fn error_type(attr: TokenStream, item: TokenStream) -> TokenStream {
     }
     .into()
 }
+
+/// Implements `ApiEntity` for a struct, along with its error struct, query builder, `DEFINE`
+/// statements and OpenAPI documentation:
+///
+/// ```ignore
+/// #[derive(Serialize, Deserialize, Clone, ApiEntity)]
+/// #[api(table = "accounts", owner_paths = ["user_id"], soft_delete)]
+/// pub struct Account {
+///     #[validate(not_empty)]
+///     pub name: String,
+///     #[validate(owner)]
+///     pub user_id: RecordId,
+/// }
+/// ```
+///
+/// `table` is required, `error`, `validator` and `validation_error` name the generated error
+/// struct and the types the validators are looked up in (`{Name}Error`, `Validator` and
+/// `ApiValidationError` by default), the other arguments set the `EntityOptions` fields.
+/// `#[validate(...)]` on the struct lists the validators of the whole entity, reported in the
+/// `_entity` field of the error: they get the listed fields, e.g. `v1_ne_v2(from, to)`, or the
+/// entity itself when none are listed. `async(validator)` runs an async validator in
+/// `validate_async` only. The errors of validators given other fields, e.g. `v1_gt_v2(end_date)`,
+/// implement `ComparedFields` to mention them. `each(...)` runs the listed validators on every
+/// item of a `Vec` field, and `nested` validates a field holding another `ApiEntity`, whose
+/// error is kept as is in the error of the field.
+#[proc_macro_derive(ApiEntity, attributes(api, validate))]
+pub fn derive_api_entity(item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(item as DeriveInput);
+    api_entity::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -0,0 +1,219 @@
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+/// The longest addresses and local parts RFC 5321 lets through, in octets.
+const MAX_ADDRESS_LENGTH: usize = 254;
+const MAX_LOCAL_PART_LENGTH: usize = 64;
+
+/// An address of the `Mailbox` syntax of RFC 5321, with the UTF-8 local parts and domains of
+/// RFC 6531: `иван.петров@почта.рф`, `"john doe"@example.com` or `user@[192.0.2.1]`.
+///
+/// The domain is kept in its ASCII form, lowercase with the internationalized labels in
+/// punycode, the form it is looked up in. The local part is kept as written since only the
+/// server of the domain knows whether its case matters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmailAddress {
+    pub local_part: String,
+    pub domain: String,
+}
+
+impl EmailAddress {
+    /// The address if it is a valid one. Display names and comments aren't part of addresses
+    /// and aren't accepted.
+    pub fn parse(address: &str) -> Option<Self> {
+        if address.len() > MAX_ADDRESS_LENGTH {
+            return None;
+        }
+        let (local_part, domain) = address.rsplit_once('@')?;
+        if local_part.len() > MAX_LOCAL_PART_LENGTH || !is_local_part(local_part) {
+            return None;
+        }
+        let domain = match domain.strip_prefix('[') {
+            Some(literal) => address_literal(literal.strip_suffix(']')?)?,
+            None => domain_name(domain)?,
+        };
+        Some(Self {
+            local_part: local_part.to_string(),
+            domain,
+        })
+    }
+
+    /// The address with its domain in ASCII, so that the same mailbox written with another
+    /// case or in Unicode is stored and looked up the same way.
+    pub fn normalized(&self) -> String {
+        format!("{}@{}", self.local_part, self.domain)
+    }
+}
+
+/// The address normalized, or `None` if it isn't a valid one.
+pub fn normalize_email(address: &str) -> Option<String> {
+    EmailAddress::parse(address).map(|address| address.normalized())
+}
+
+/// `atext` of RFC 5322, widened to non-ASCII characters by RFC 6531.
+fn is_atext(c: char) -> bool {
+    c.is_ascii_alphanumeric()
+        || "!#$%&'*+-/=?^_`{|}~".contains(c)
+        || (!c.is_ascii() && !c.is_control())
+}
+
+/// A `Dot-string` or a `Quoted-string`.
+fn is_local_part(local_part: &str) -> bool {
+    match local_part
+        .strip_prefix('"')
+        .and_then(|quoted| quoted.strip_suffix('"'))
+    {
+        Some(quoted) => is_quoted_content(quoted),
+        None => local_part
+            .split('.')
+            .all(|atom| !atom.is_empty() && atom.chars().all(is_atext)),
+    }
+}
+
+/// Printable characters other than `"` and `\`, which can only appear escaped by `\`.
+fn is_quoted_content(quoted: &str) -> bool {
+    let mut chars = quoted.chars();
+    while let Some(c) = chars.next() {
+        let c = match c {
+            '\\' => match chars.next() {
+                Some(escaped) => escaped,
+                None => return false,
+            },
+            '"' => return false,
+            c => c,
+        };
+        if c.is_control() {
+            return false;
+        }
+    }
+    true
+}
+
+/// An IPv4 or `IPv6:` address between brackets, in its canonical form.
+fn address_literal(literal: &str) -> Option<String> {
+    match literal.get(..5) {
+        Some(tag) if tag.eq_ignore_ascii_case("IPv6:") => literal[5..]
+            .parse::<Ipv6Addr>()
+            .ok()
+            .map(|ip| format!("[IPv6:{}]", ip)),
+        _ => literal
+            .parse::<Ipv4Addr>()
+            .ok()
+            .map(|ip| format!("[{}]", ip)),
+    }
+}
+
+/// A domain of at least two labels with an alphabetic or punycode top-level one, in ASCII.
+fn domain_name(domain: &str) -> Option<String> {
+    let domain = idna::domain_to_ascii_strict(domain).ok()?;
+    let (_, top_level) = domain.rsplit_once('.')?;
+    let is_top_level = top_level.len() >= 2
+        && (top_level.chars().all(|c| c.is_ascii_alphabetic()) || top_level.starts_with("xn--"));
+    is_top_level.then_some(domain)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn international_domains() {
+        assert_eq!(
+            normalize_email("иван@почта.рф").as_deref(),
+            Some("иван@xn--80a1acny.xn--p1ai")
+        );
+        assert_eq!(
+            normalize_email("иван@xn--80a1acny.xn--p1ai").as_deref(),
+            Some("иван@xn--80a1acny.xn--p1ai")
+        );
+        assert_eq!(
+            normalize_email("user@Bücher.Example").as_deref(),
+            Some("user@xn--bcher-kva.example")
+        );
+    }
+
+    #[test]
+    fn mixed_case_domains() {
+        assert_eq!(
+            normalize_email("John.Doe@Example.COM").as_deref(),
+            Some("John.Doe@example.com")
+        );
+    }
+
+    #[test]
+    fn local_parts() {
+        let address = EmailAddress::parse("иван.петров@example.com").unwrap();
+        assert_eq!(address.local_part, "иван.петров");
+        assert!(EmailAddress::parse("first+tag!#$%&'*/=?^_`{|}~-@example.com").is_some());
+        assert_eq!(
+            EmailAddress::parse(r#""john doe"@example.com"#)
+                .unwrap()
+                .local_part,
+            r#""john doe""#
+        );
+        assert!(EmailAddress::parse(r#""john..doe"@example.com"#).is_some());
+        assert!(EmailAddress::parse(r#""a\"b\\c"@example.com"#).is_some());
+        assert!(EmailAddress::parse(r#""a"b"@example.com"#).is_none());
+        assert!(EmailAddress::parse(r#""a\"@example.com"#).is_none());
+        assert!(EmailAddress::parse("\"a\tb\"@example.com").is_none());
+        assert!(EmailAddress::parse("john doe@example.com").is_none());
+    }
+
+    #[test]
+    fn address_literals() {
+        assert_eq!(
+            normalize_email("user@[192.0.2.1]").as_deref(),
+            Some("user@[192.0.2.1]")
+        );
+        assert_eq!(
+            normalize_email("user@[ipv6:2001:DB8:0::1]").as_deref(),
+            Some("user@[IPv6:2001:db8::1]")
+        );
+        assert!(normalize_email("user@[300.0.2.1]").is_none());
+        assert!(normalize_email("user@[2001:db8::1]").is_none());
+        assert!(normalize_email("user@[192.0.2.1").is_none());
+    }
+
+    #[test]
+    fn length_limits() {
+        let local_part = "a".repeat(MAX_LOCAL_PART_LENGTH);
+        assert!(EmailAddress::parse(&format!("{}@example.com", local_part)).is_some());
+        assert!(EmailAddress::parse(&format!("{}a@example.com", local_part)).is_none());
+        let domain = |last_label: usize| {
+            format!(
+                "{}.{}.{}.com",
+                "b".repeat(63),
+                "c".repeat(63),
+                "d".repeat(last_label)
+            )
+        };
+        let longest = format!("{}@{}", local_part, domain(57));
+        assert_eq!(longest.len(), MAX_ADDRESS_LENGTH);
+        assert!(EmailAddress::parse(&longest).is_some());
+        assert!(EmailAddress::parse(&format!("{}@{}", local_part, domain(58))).is_none());
+        assert!(EmailAddress::parse(&format!("a@{}.com", "b".repeat(64))).is_none());
+    }
+
+    #[test]
+    fn rejected() {
+        for address in [
+            "",
+            "example.com",
+            "@example.com",
+            "a@",
+            "a@example..com",
+            "a@.example.com",
+            "a@example.com.",
+            ".a@example.com",
+            "a.@example.com",
+            "a..b@example.com",
+            "a@localhost",
+            "a@example.c0m",
+            "a@-example.com",
+            "a@exa_mple.com",
+            "John Doe <a@example.com>",
+            "a@example.com (comment)",
+        ] {
+            assert!(EmailAddress::parse(address).is_none(), "{}", address);
+        }
+    }
+}
@@ -1,8 +1,16 @@
 #![allow(unused_imports)]
+mod email;
 mod error;
 #[cfg(feature = "actix-surreal-impl")]
 mod implementations;
+mod messages;
+mod password_policy;
+mod regexes;
+pub mod validators;
 
+pub use email::{normalize_email, EmailAddress};
 pub use error::*;
 #[cfg(feature = "actix-surreal-impl")]
-pub use implementations::*;
\ No newline at end of file
+pub use implementations::*;
+pub use messages::MessageCatalog;
+pub use password_policy::PasswordPolicy;
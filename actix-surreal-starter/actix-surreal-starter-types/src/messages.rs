@@ -0,0 +1,76 @@
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Messages of the errors by language and code, with `{name}` placeholders for the parameters
+/// of the errors, e.g. `"Password must be at least {min} characters long."`.
+///
+/// Errors are rendered from their serialized shape: `{"code": "...", "params": {...}}` for the
+/// validation errors, or the name of the variant for unit variants like `"EmailTaken"`.
+#[derive(Debug, Clone, Default)]
+pub struct MessageCatalog {
+    languages: HashMap<String, HashMap<String, String>>,
+}
+
+impl MessageCatalog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the messages of a language, keyed by the codes of the errors.
+    pub fn language<C: Into<String>, M: Into<String>>(
+        mut self,
+        language: &str,
+        messages: impl IntoIterator<Item = (C, M)>,
+    ) -> Self {
+        self.languages
+            .entry(language.to_string())
+            .or_default()
+            .extend(
+                messages
+                    .into_iter()
+                    .map(|(code, message)| (code.into(), message.into())),
+            );
+        self
+    }
+
+    /// Adds the messages of a language from a JSON object keyed by the codes of the errors,
+    /// like the `error_messages` translations of the static pages.
+    pub fn language_json(self, language: &str, messages: &str) -> Result<Self, serde_json::Error> {
+        let messages: HashMap<String, String> = serde_json::from_str(messages)?;
+        Ok(self.language(language, messages))
+    }
+
+    /// The message of the error in the language with its parameters filled in, or its code
+    /// if the catalog has no message for it.
+    pub fn render(&self, language: &str, error: &impl Serialize) -> String {
+        let error = serde_json::to_value(error).unwrap_or(Value::Null);
+        let code = match &error {
+            Value::String(code) => code.as_str(),
+            error => match error.get("code").and_then(Value::as_str) {
+                Some(code) => code,
+                None => return error.to_string(),
+            },
+        };
+        let Some(message) = self
+            .languages
+            .get(language)
+            .and_then(|messages| messages.get(code))
+        else {
+            return code.to_string();
+        };
+        match error.get("params").and_then(Value::as_object) {
+            Some(params) => params
+                .iter()
+                .fold(message.clone(), |message, (name, value)| {
+                    let value = match value {
+                        Value::String(value) => value.clone(),
+                        Value::Null => String::new(),
+                        value => value.to_string(),
+                    };
+                    message.replace(&format!("{{{}}}", name), &value)
+                }),
+            None => message.clone(),
+        }
+    }
+}
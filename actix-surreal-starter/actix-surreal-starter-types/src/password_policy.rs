@@ -0,0 +1,264 @@
+use crate::validators::ValidationError;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::sync::{Arc, LazyLock, RwLock};
+
+static POLICIES: LazyLock<RwLock<HashMap<String, Arc<PasswordPolicy>>>> =
+    LazyLock::new(Default::default);
+/// Known leaked passwords, lowercase, shared by the policies.
+static BREACHED_PASSWORDS: LazyLock<RwLock<HashSet<String>>> = LazyLock::new(Default::default);
+
+/// The rules of the `password` validator, installed under a name at startup and referred to by
+/// that name, e.g. `#[validate(password("users"))]`. The clients install the policies the
+/// server lists at `/password_policies` to check the same rules.
+///
+/// The character classes are Unicode ones, so `"Пароль1"` has an uppercase letter, a lowercase
+/// one and a digit.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PasswordPolicy {
+    pub min_length: usize,
+    pub max_length: usize,
+    pub require_uppercase: bool,
+    pub require_lowercase: bool,
+    pub require_digit: bool,
+    /// Characters that are neither letters, digits nor spaces.
+    pub require_special: bool,
+    /// Whether characters outside of printable ASCII are allowed.
+    pub allow_unicode: bool,
+    pub allow_spaces: bool,
+    /// How similar, from 0 to 1, the password may be to the fields given to `password_unlike`,
+    /// e.g. the login. Passwords containing the field, or the part of an email before `@`, or
+    /// contained in it count as identical. `None` allows any.
+    pub max_similarity: Option<f64>,
+    /// Whether the passwords loaded by `load_breached_passwords` are rejected, regardless of
+    /// case.
+    pub reject_breached: bool,
+}
+
+impl Default for PasswordPolicy {
+    fn default() -> Self {
+        Self::basic()
+    }
+}
+
+impl PasswordPolicy {
+    /// At least 6 characters with a digit.
+    pub fn basic() -> Self {
+        Self {
+            min_length: 6,
+            max_length: 64,
+            require_uppercase: false,
+            require_lowercase: false,
+            require_digit: true,
+            require_special: false,
+            allow_unicode: true,
+            allow_spaces: false,
+            max_similarity: Some(0.8),
+            reject_breached: true,
+        }
+    }
+
+    /// At least 8 characters with a digit, an uppercase and a lowercase letter.
+    pub fn moderate() -> Self {
+        Self {
+            min_length: 8,
+            require_uppercase: true,
+            require_lowercase: true,
+            ..Self::basic()
+        }
+    }
+
+    /// At least 12 characters with a digit, an uppercase and a lowercase letter and a special
+    /// character.
+    pub fn strict() -> Self {
+        Self {
+            min_length: 12,
+            require_special: true,
+            max_similarity: Some(0.6),
+            ..Self::moderate()
+        }
+    }
+
+    /// Adds the passwords of a local file, one per line, to the breached ones, returning how
+    /// many the file has. Empty lines are skipped.
+    pub fn load_breached_passwords(path: impl AsRef<Path>) -> std::io::Result<usize> {
+        let passwords = std::fs::read_to_string(path)?;
+        let passwords: Vec<String> = passwords
+            .lines()
+            .map(str::trim)
+            .filter(|password| !password.is_empty())
+            .map(str::to_lowercase)
+            .collect();
+        let count = passwords.len();
+        let mut breached = BREACHED_PASSWORDS
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        breached.extend(passwords);
+        Ok(count)
+    }
+
+    /// Makes the policy the one of `name`, replacing the previous one.
+    pub fn install(self, name: &str) {
+        let mut policies = POLICIES
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        policies.insert(name.to_string(), Arc::new(self));
+    }
+
+    /// The policy installed under `name`, if any.
+    pub fn get(name: &str) -> Option<Arc<PasswordPolicy>> {
+        let policies = POLICIES
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        policies.get(name).cloned()
+    }
+
+    /// The policy installed under `name`. Panics if none was, since a misspelled name would
+    /// otherwise check passwords against rules nobody chose.
+    pub fn named(name: &str) -> Arc<PasswordPolicy> {
+        Self::get(name).unwrap_or_else(|| panic!("no password policy is installed as `{}`", name))
+    }
+
+    /// The installed policies by name.
+    pub fn installed() -> HashMap<String, PasswordPolicy> {
+        let policies = POLICIES
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        policies
+            .iter()
+            .map(|(name, policy)| (name.clone(), PasswordPolicy::clone(policy)))
+            .collect()
+    }
+
+    pub fn check(&self, password: &str) -> Result<(), ValidationError> {
+        let length = password.chars().count();
+        if length < self.min_length {
+            return Err(ValidationError::PasswordTooShort {
+                min: self.min_length,
+                actual: length,
+            });
+        }
+        if length > self.max_length {
+            return Err(ValidationError::PasswordTooLong {
+                max: self.max_length,
+                actual: length,
+            });
+        }
+        if !self.allow_spaces && password.chars().any(char::is_whitespace) {
+            return Err(ValidationError::PasswordMustNotContainSpaces);
+        }
+        let invalid = |c: char| c.is_control() || (!self.allow_unicode && !c.is_ascii());
+        if password.chars().any(invalid) {
+            return Err(ValidationError::PasswordContainsInvalidCharacters);
+        }
+        let classes = [
+            (
+                self.require_digit,
+                char::is_numeric as fn(char) -> bool,
+                ValidationError::PasswordMustContainDigit,
+            ),
+            (
+                self.require_uppercase,
+                char::is_uppercase,
+                ValidationError::PasswordMustContainUppercase,
+            ),
+            (
+                self.require_lowercase,
+                char::is_lowercase,
+                ValidationError::PasswordMustContainLowercase,
+            ),
+            (
+                self.require_special,
+                is_special,
+                ValidationError::PasswordMustContainSpecial,
+            ),
+        ];
+        for (required, class, error) in classes {
+            if required && !password.chars().any(class) {
+                return Err(error);
+            }
+        }
+        if self.reject_breached && is_breached(password) {
+            return Err(ValidationError::PasswordBreached);
+        }
+        Ok(())
+    }
+
+    /// Whether the password is more similar to `other` than `max_similarity` allows.
+    pub fn is_too_similar(&self, password: &str, other: &str) -> bool {
+        let Some(max_similarity) = self.max_similarity else {
+            return false;
+        };
+        let password = password.to_lowercase();
+        let other = other.to_lowercase();
+        let other = other.split('@').next().unwrap_or_default();
+        // Too short to be guessed from.
+        if other.chars().count() < 3 {
+            return false;
+        }
+        password.contains(other)
+            || other.contains(&password)
+            || similarity(&password, other) > max_similarity
+    }
+}
+
+fn is_breached(password: &str) -> bool {
+    let breached = BREACHED_PASSWORDS
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    !breached.is_empty() && breached.contains(&password.to_lowercase())
+}
+
+fn is_special(c: char) -> bool {
+    !c.is_alphanumeric() && !c.is_whitespace()
+}
+
+/// 1 minus the edit distance of the values relative to the longest one.
+fn similarity(a: &str, b: &str) -> f64 {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let longest = a.len().max(b.len());
+    if longest == 0 {
+        return 1.0;
+    }
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, a_char) in a.iter().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != b_char);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+    1.0 - previous[b.len()] as f64 / longest as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The policies are global, so every test installs its own names.
+
+    #[test]
+    fn install() {
+        PasswordPolicy::strict().install("install_test");
+        assert_eq!(PasswordPolicy::named("install_test").min_length, 12);
+        assert_eq!(PasswordPolicy::installed()["install_test"].min_length, 12);
+        PasswordPolicy::basic().install("install_test");
+        assert_eq!(PasswordPolicy::named("install_test").min_length, 6);
+    }
+
+    #[test]
+    fn unknown_name() {
+        assert!(PasswordPolicy::get("unknown_name_test").is_none());
+        assert!(!PasswordPolicy::installed().contains_key("unknown_name_test"));
+    }
+
+    #[test]
+    #[should_panic(expected = "no password policy is installed as `named_test`")]
+    fn named_unknown() {
+        PasswordPolicy::named("named_test");
+    }
+}
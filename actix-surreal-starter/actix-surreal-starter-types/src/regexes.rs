@@ -0,0 +1,16 @@
+use regex::Regex;
+use std::sync::LazyLock;
+
+macro_rules! regexes {
+    {$($name:ident => $value:literal)*} => {
+        $(
+        pub static $name: LazyLock<Regex> = LazyLock::new(|| Regex::new($value).unwrap());
+        )*
+    };
+}
+
+regexes! {
+    URL => r"^https?://[^\s/?#:@]+(?::\d{1,5})?(?:[/?#]\S*)?$"
+    LANGUAGE_TAG => r"^[a-zA-Z]{2,3}(?:-[a-zA-Z]{3}){0,3}(?:-[a-zA-Z]{4})?(?:-(?:[a-zA-Z]{2}|\d{3}))?(?:-(?:[a-zA-Z\d]{5,8}|\d[a-zA-Z\d]{3}))*(?:-x(?:-[a-zA-Z\d]{1,8})+)?$"
+    IBAN => r"^[A-Z]{2}\d{2}[A-Z\d]{11,30}$"
+}
@@ -0,0 +1,812 @@
+use crate::email::EmailAddress;
+use crate::password_policy::PasswordPolicy;
+use crate::regexes;
+use actix_surreal_starter_macros::{impl_display_for_error, impl_validators};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock, Mutex};
+use thiserror::Error;
+
+/// Serialized as `{"code": "StringTooShort", "params": {"min": 8, "actual": 3}}`, without
+/// `params` for the variants that have none. `other` is the field a value was compared to.
+#[derive(Debug, Error, Clone, Serialize, Deserialize)]
+#[serde(tag = "code", content = "params")]
+pub enum ValidationError {
+    StringIsEmpty,
+    StringTooShort {
+        min: usize,
+        actual: usize,
+    },
+    StringTooLong {
+        max: usize,
+        actual: usize,
+    },
+    StringNotTrimmed,
+    PatternMismatch {
+        pattern: String,
+    },
+    /// The pattern given to `matches` isn't a valid regular expression.
+    PatternInvalid {
+        pattern: String,
+        error: String,
+    },
+    NotOneOf {
+        allowed: Vec<String>,
+    },
+    UrlInvalid,
+    CurrencyCodeInvalid,
+    LanguageTagInvalid,
+    IbanInvalid,
+    GTZero,
+    GEZero,
+    LTZero,
+    LEZero,
+    EQZero,
+    NEZero,
+    NumberTooSmall {
+        min: f64,
+        actual: f64,
+    },
+    NumberTooLarge {
+        max: f64,
+        actual: f64,
+    },
+    NotMultipleOf {
+        step: f64,
+    },
+    TooManyDecimalPlaces {
+        max: u32,
+        actual: u32,
+    },
+    NumberNotFinite,
+    IntegerOutOfRange {
+        min: i64,
+        max: i64,
+    },
+    V1LTV2 {
+        other: Option<String>,
+    },
+    V1LEV2 {
+        other: Option<String>,
+    },
+    V1GTV2 {
+        other: Option<String>,
+    },
+    V1GEV2 {
+        other: Option<String>,
+    },
+    V1EQV2 {
+        other: Option<String>,
+    },
+    V1NEV2 {
+        other: Option<String>,
+    },
+    EmailFormatInvalid,
+    PasswordTooShort {
+        min: usize,
+        actual: usize,
+    },
+    PasswordTooLong {
+        max: usize,
+        actual: usize,
+    },
+    PasswordMustNotContainSpaces,
+    PasswordContainsInvalidCharacters,
+    PasswordMustContainUppercase,
+    PasswordMustContainLowercase,
+    PasswordMustContainDigit,
+    PasswordMustContainSpecial,
+    PasswordBreached,
+    PasswordTooSimilar {
+        other: Option<String>,
+    },
+    /// No policy is installed under the name given to `password`, e.g. on a client that hasn't
+    /// loaded the policies of the server yet.
+    UnknownPasswordPolicy {
+        name: String,
+    },
+    ValueIsSome,
+    ValueIsNone,
+    TooFewItems {
+        min: usize,
+        actual: usize,
+    },
+    TooManyItems {
+        max: usize,
+        actual: usize,
+    },
+    ItemsNotUnique {
+        index: usize,
+    },
+    /// An error of the item at `index` of a collection, from the validators of `each(...)`.
+    InvalidItem {
+        index: usize,
+        error: Box<ValidationError>,
+    },
+}
+
+impl_display_for_error!(ValidationError);
+
+/// Results with parameters can't follow `=>` in `impl_validators!`.
+fn fail_if(condition: bool, error: ValidationError) -> Result<(), ValidationError> {
+    match condition {
+        true => Err(error),
+        false => Ok(()),
+    }
+}
+
+/// The policy the `password` validators refer to by `name`.
+fn installed_policy(name: &str) -> Result<Arc<PasswordPolicy>, ValidationError> {
+    PasswordPolicy::get(name).ok_or_else(|| ValidationError::UnknownPasswordPolicy {
+        name: name.to_string(),
+    })
+}
+
+/// Errors of validators comparing a field to other fields, e.g. `v1_gt_v2(end_date)`, which
+/// `#[derive(ApiEntity)]` tells the names of the fields it passed, for the error messages.
+pub trait ComparedFields {
+    fn compared_to(self, fields: &[&'static str]) -> Self;
+}
+
+/// Errors of validators applied to every item of a collection by `each(...)`, which
+/// `#[derive(ApiEntity)]` tells the index of the item they were found at.
+pub trait ItemError {
+    fn at_index(self, index: usize) -> Self;
+}
+
+impl ItemError for ValidationError {
+    fn at_index(self, index: usize) -> Self {
+        ValidationError::InvalidItem {
+            index,
+            error: Box::new(self),
+        }
+    }
+}
+
+/// The active ISO 4217 codes, sorted.
+const CURRENCY_CODES: &[&str] = &[
+    "AED", "AFN", "ALL", "AMD", "ANG", "AOA", "ARS", "AUD", "AWG", "AZN", "BAM", "BBD", "BDT",
+    "BGN", "BHD", "BIF", "BMD", "BND", "BOB", "BOV", "BRL", "BSD", "BTN", "BWP", "BYN", "BZD",
+    "CAD", "CDF", "CHE", "CHF", "CHW", "CLF", "CLP", "CNY", "COP", "COU", "CRC", "CUP", "CVE",
+    "CZK", "DJF", "DKK", "DOP", "DZD", "EGP", "ERN", "ETB", "EUR", "FJD", "FKP", "GBP", "GEL",
+    "GHS", "GIP", "GMD", "GNF", "GTQ", "GYD", "HKD", "HNL", "HTG", "HUF", "IDR", "ILS", "INR",
+    "IQD", "IRR", "ISK", "JMD", "JOD", "JPY", "KES", "KGS", "KHR", "KMF", "KPW", "KRW", "KWD",
+    "KYD", "KZT", "LAK", "LBP", "LKR", "LRD", "LSL", "LYD", "MAD", "MDL", "MGA", "MKD", "MMK",
+    "MNT", "MOP", "MRU", "MUR", "MVR", "MWK", "MXN", "MXV", "MYR", "MZN", "NAD", "NGN", "NIO",
+    "NOK", "NPR", "NZD", "OMR", "PAB", "PEN", "PGK", "PHP", "PKR", "PLN", "PYG", "QAR", "RON",
+    "RSD", "RUB", "RWF", "SAR", "SBD", "SCR", "SDG", "SEK", "SGD", "SHP", "SLE", "SOS", "SRD",
+    "SSP", "STN", "SVC", "SYP", "SZL", "THB", "TJS", "TMT", "TND", "TOP", "TRY", "TTD", "TWD",
+    "TZS", "UAH", "UGX", "USD", "USN", "UYI", "UYU", "UYW", "UZS", "VED", "VES", "VND", "VUV",
+    "WST", "XAF", "XAG", "XAU", "XBA", "XBB", "XBC", "XBD", "XCD", "XCG", "XDR", "XOF", "XPD",
+    "XPF", "XPT", "XSU", "XTS", "XUA", "XXX", "YER", "ZAR", "ZMW", "ZWG",
+];
+
+/// Whether the value matches the pattern, compiled once per pattern. `#[derive(ApiEntity)]`
+/// rejects invalid literal patterns, the others fail every value with
+/// [`ValidationError::PatternInvalid`].
+fn matches_pattern(value: &str, pattern: &str) -> Result<bool, ValidationError> {
+    static PATTERNS: LazyLock<Mutex<HashMap<String, Regex>>> = LazyLock::new(Default::default);
+    let mut patterns = PATTERNS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if let Some(regex) = patterns.get(pattern) {
+        return Ok(regex.is_match(value));
+    }
+    let regex = Regex::new(pattern).map_err(|e| ValidationError::PatternInvalid {
+        pattern: pattern.to_string(),
+        error: e.to_string(),
+    })?;
+    Ok(patterns
+        .entry(pattern.to_string())
+        .or_insert(regex)
+        .is_match(value))
+}
+
+/// The ISO 13616 check: the country code and the check digits moved to the end, with the
+/// letters as numbers from 10, give 1 modulo 97. Spaces between the groups are allowed.
+fn is_iban(value: &str) -> bool {
+    let iban: String = value.chars().filter(|c| *c != ' ').collect();
+    if !regexes::IBAN.is_match(&iban) {
+        return false;
+    }
+    let (head, tail) = iban.split_at(4);
+    let remainder = tail.chars().chain(head.chars()).fold(0, |remainder, c| {
+        let digit = c.to_digit(36).unwrap_or(0);
+        let shift = if digit >= 10 { 100 } else { 10 };
+        (remainder * shift + digit) % 97
+    });
+    remainder == 1
+}
+
+/// The numbers of the numeric validators, reported as `f64` in the error parameters.
+pub trait Number: PartialOrd + Copy {
+    fn to_f64(self) -> f64;
+}
+
+macro_rules! impl_number {
+    ($($type:ty),*) => {
+        $(
+        impl Number for $type {
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+        }
+        )*
+    };
+}
+
+impl_number!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, f32, f64);
+
+/// The digits after the decimal point of the shortest representation of the value, which is
+/// how the value was written unless it was written with more digits than `f64` holds.
+fn decimal_places(value: f64) -> u32 {
+    let value = value.to_string();
+    value
+        .split_once('.')
+        .map_or(0, |(_, decimals)| decimals.len() as u32)
+}
+
+/// Whether `value` is a whole multiple of `step`, within the precision of `f64`.
+fn is_multiple(value: f64, step: f64) -> bool {
+    let ratio = value / step;
+    ratio.is_finite() && (ratio - ratio.round()).abs() <= 1e-9 * ratio.abs().max(1.0)
+}
+
+fn integer_in<T: TryInto<i64> + Copy>(value: T, min: i64, max: i64) -> Result<(), ValidationError> {
+    match value.try_into() {
+        Ok(value) if (min..=max).contains(&value) => Ok(()),
+        _ => Err(ValidationError::IntegerOutOfRange { min, max }),
+    }
+}
+
+impl ComparedFields for ValidationError {
+    fn compared_to(self, fields: &[&'static str]) -> Self {
+        let other = fields.first().map(|field| field.to_string());
+        match self {
+            ValidationError::V1LTV2 { .. } => ValidationError::V1LTV2 { other },
+            ValidationError::V1LEV2 { .. } => ValidationError::V1LEV2 { other },
+            ValidationError::V1GTV2 { .. } => ValidationError::V1GTV2 { other },
+            ValidationError::V1GEV2 { .. } => ValidationError::V1GEV2 { other },
+            ValidationError::V1EQV2 { .. } => ValidationError::V1EQV2 { other },
+            ValidationError::V1NEV2 { .. } => ValidationError::V1NEV2 { other },
+            ValidationError::PasswordTooSimilar { .. } => {
+                ValidationError::PasswordTooSimilar { other }
+            }
+            error => error,
+        }
+    }
+}
+
+impl_validators! {
+    (trait_name: pub DefaultValidations, names: pub DEFAULT_VALIDATIONS, parameter_name: v, error: ValidationError) {
+        not_empty(&String) {
+            v.trim().len() == 0 => StringIsEmpty
+        }
+        gt_zero<T: PartialOrd|Default>(&T) {
+            *v <= T::default() => LEZero
+        }
+        ge_zero<T: PartialOrd|Default>(&T) {
+            *v < T::default() => LTZero
+        }
+        lt_zero<T: PartialOrd|Default>(&T) {
+            *v >= T::default() => GEZero
+        }
+        le_zero<T: PartialOrd|Default>(&T) {
+            *v > T::default() => GTZero
+        }
+        eq_zero<T: PartialOrd|Default>(&T) {
+            *v != T::default() => NEZero
+        }
+        ne_zero<T: PartialOrd|Default>(&T) {
+            *v == T::default() => EQZero
+        }
+        in_range<T: Number>(&T, T, T) {
+            match (v.0.to_f64(), v.1.to_f64(), v.2.to_f64()) {
+                (actual, min, _) if *v.0 < v.1 => Err(ValidationError::NumberTooSmall { min, actual }),
+                (actual, _, max) if *v.0 > v.2 => Err(ValidationError::NumberTooLarge { max, actual }),
+                _ => Ok(()),
+            }
+        }
+        multiple_of<T: Number>(&T, T) {
+            fail_if(!is_multiple(v.0.to_f64(), v.1.to_f64()), ValidationError::NotMultipleOf { step: v.1.to_f64() })
+        }
+        finite<T: Number>(&T) {
+            !v.to_f64().is_finite() => NumberNotFinite
+        }
+        max_decimal_places<T: Number>(&T, u32) {
+            match decimal_places(v.0.to_f64()) {
+                actual if actual > v.1 => Err(ValidationError::TooManyDecimalPlaces { max: v.1, actual }),
+                _ => Ok(()),
+            }
+        }
+        fits_i32<T: TryInto<i64>|Copy>(&T) {
+            integer_in(*v, i32::MIN as i64, i32::MAX as i64)
+        }
+        fits_u32<T: TryInto<i64>|Copy>(&T) {
+            integer_in(*v, 0, u32::MAX as i64)
+        }
+        fits_i64<T: TryInto<i64>|Copy>(&T) {
+            integer_in(*v, i64::MIN, i64::MAX)
+        }
+        length_at_least(&String, usize) {
+            match v.0.chars().count() {
+                actual if actual < v.1 => Err(ValidationError::StringTooShort { min: v.1, actual }),
+                _ => Ok(()),
+            }
+        }
+        length_at_most(&String, usize) {
+            match v.0.chars().count() {
+                actual if actual > v.1 => Err(ValidationError::StringTooLong { max: v.1, actual }),
+                _ => Ok(()),
+            }
+        }
+        length_in_range(&String, usize, usize) {
+            match v.0.chars().count() {
+                actual if actual < v.1 => Err(ValidationError::StringTooShort { min: v.1, actual }),
+                actual if actual > v.2 => Err(ValidationError::StringTooLong { max: v.2, actual }),
+                _ => Ok(()),
+            }
+        }
+        v1_ge_v2<T: PartialOrd>(&T, &T) {
+            fail_if(v.0 < v.1, ValidationError::V1LTV2 { other: None })
+        }
+        v1_gt_v2<T: PartialOrd>(&T, &T) {
+            fail_if(v.0 <= v.1, ValidationError::V1LEV2 { other: None })
+        }
+        v1_le_v2<T: PartialOrd>(&T, &T) {
+            fail_if(v.0 > v.1, ValidationError::V1GTV2 { other: None })
+        }
+        v1_lt_v2<T: PartialOrd>(&T, &T) {
+            fail_if(v.0 >= v.1, ValidationError::V1GEV2 { other: None })
+        }
+        v1_eq_v2<T: PartialOrd>(&T, &T) {
+            fail_if(v.0 != v.1, ValidationError::V1NEV2 { other: None })
+        }
+        v1_ne_v2<T: PartialOrd>(&T, &T) {
+            fail_if(v.0 == v.1, ValidationError::V1EQV2 { other: None })
+        }
+        trimmed(&str) {
+            v.trim() != v => StringNotTrimmed
+        }
+        matches(&String, &str) {
+            fail_if(!matches_pattern(v.0, v.1)?, ValidationError::PatternMismatch { pattern: v.1.to_string() })
+        }
+        one_of(&String, &[&str]) {
+            fail_if(
+                !v.1.contains(&v.0.as_str()),
+                ValidationError::NotOneOf { allowed: v.1.iter().map(|value| value.to_string()).collect() },
+            )
+        }
+        url(&str) {
+            !regexes::URL.is_match(v) => UrlInvalid
+        }
+        iso4217_currency(&str) {
+            CURRENCY_CODES.binary_search(&v).is_err() => CurrencyCodeInvalid
+        }
+        bcp47_language(&str) {
+            !regexes::LANGUAGE_TAG.is_match(v) => LanguageTagInvalid
+        }
+        iban(&str) {
+            !is_iban(v) => IbanInvalid
+        }
+        email_format(&String) {
+            EmailAddress::parse(v).is_none() => EmailFormatInvalid
+        }
+        password(&String, &str) {
+            installed_policy(v.1)?.check(v.0)
+        }
+        password_unlike(&String, &str, &String) {
+            fail_if(
+                installed_policy(v.1)?.is_too_similar(v.0, v.2),
+                ValidationError::PasswordTooSimilar { other: None },
+            )
+        }
+        min_items<T>(&[T], usize) {
+            fail_if(v.0.len() < v.1, ValidationError::TooFewItems { min: v.1, actual: v.0.len() })
+        }
+        max_items<T>(&[T], usize) {
+            fail_if(v.0.len() > v.1, ValidationError::TooManyItems { max: v.1, actual: v.0.len() })
+        }
+        unique<T: PartialEq>(&[T]) {
+            match v.iter().enumerate().find(|(index, item)| v[..*index].contains(item)) {
+                Some((index, _)) => Err(ValidationError::ItemsNotUnique { index }),
+                None => Ok(()),
+            }
+        }
+        none<T>(&Option<T>) {
+            v.is_some() => ValueIsSome
+        }
+        some<T>(&Option<T>) {
+            v.is_none() => ValueIsNone
+        }
+        optional_v2_gt_v1<T: PartialOrd>(&T, &Option<T>) {
+            match v.1 {
+                Some(value) => Self::v1_le_v2((v.0, value)),
+                None => Ok(()),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Validator;
+    impl DefaultValidations for Validator {}
+
+    #[test]
+    fn zero_comparisons() {
+        assert!(Validator::eq_zero(&0).is_ok());
+        assert!(matches!(
+            Validator::eq_zero(&-1),
+            Err(ValidationError::NEZero)
+        ));
+        assert!(matches!(
+            Validator::eq_zero(&0.5),
+            Err(ValidationError::NEZero)
+        ));
+        assert!(Validator::ne_zero(&-1).is_ok());
+        assert!(matches!(
+            Validator::ne_zero(&0),
+            Err(ValidationError::EQZero)
+        ));
+        assert!(matches!(
+            Validator::ne_zero(&0.0),
+            Err(ValidationError::EQZero)
+        ));
+    }
+
+    #[test]
+    fn sign_comparisons() {
+        assert!(Validator::gt_zero(&1).is_ok());
+        assert!(Validator::gt_zero(&f64::MIN_POSITIVE).is_ok());
+        assert!(matches!(
+            Validator::gt_zero(&0),
+            Err(ValidationError::LEZero)
+        ));
+        assert!(matches!(
+            Validator::gt_zero(&-1),
+            Err(ValidationError::LEZero)
+        ));
+        assert!(Validator::ge_zero(&1).is_ok());
+        assert!(Validator::ge_zero(&0).is_ok());
+        assert!(Validator::ge_zero(&-0.0).is_ok());
+        assert!(matches!(
+            Validator::ge_zero(&-1),
+            Err(ValidationError::LTZero)
+        ));
+        assert!(Validator::lt_zero(&-1).is_ok());
+        assert!(Validator::lt_zero(&-f64::MIN_POSITIVE).is_ok());
+        assert!(matches!(
+            Validator::lt_zero(&0),
+            Err(ValidationError::GEZero)
+        ));
+        assert!(matches!(
+            Validator::lt_zero(&1),
+            Err(ValidationError::GEZero)
+        ));
+        assert!(Validator::le_zero(&-1).is_ok());
+        assert!(Validator::le_zero(&0).is_ok());
+        assert!(matches!(
+            Validator::le_zero(&1),
+            Err(ValidationError::GTZero)
+        ));
+        // `None` is the default of `Option`, so it counts as zero.
+        assert!(matches!(
+            Validator::gt_zero(&None::<i32>),
+            Err(ValidationError::LEZero)
+        ));
+        assert!(Validator::ge_zero(&None::<i32>).is_ok());
+        assert!(Validator::gt_zero(&Some(1)).is_ok());
+        assert!(matches!(
+            Validator::le_zero(&Some(0)),
+            Err(ValidationError::GTZero)
+        ));
+    }
+
+    #[test]
+    fn order_comparisons() {
+        assert!(Validator::v1_gt_v2((&2, &1)).is_ok());
+        assert!(matches!(
+            Validator::v1_gt_v2((&1, &1)),
+            Err(ValidationError::V1LEV2 { other: None })
+        ));
+        assert!(matches!(
+            Validator::v1_gt_v2((&0, &1)),
+            Err(ValidationError::V1LEV2 { .. })
+        ));
+        assert!(Validator::v1_ge_v2((&2, &1)).is_ok());
+        assert!(Validator::v1_ge_v2((&1, &1)).is_ok());
+        assert!(matches!(
+            Validator::v1_ge_v2((&0, &1)),
+            Err(ValidationError::V1LTV2 { other: None })
+        ));
+        assert!(Validator::v1_lt_v2((&0, &1)).is_ok());
+        assert!(matches!(
+            Validator::v1_lt_v2((&1, &1)),
+            Err(ValidationError::V1GEV2 { other: None })
+        ));
+        assert!(matches!(
+            Validator::v1_lt_v2((&2, &1)),
+            Err(ValidationError::V1GEV2 { .. })
+        ));
+        assert!(Validator::v1_le_v2((&0, &1)).is_ok());
+        assert!(Validator::v1_le_v2((&1, &1)).is_ok());
+        assert!(matches!(
+            Validator::v1_le_v2((&2, &1)),
+            Err(ValidationError::V1GTV2 { other: None })
+        ));
+        assert!(Validator::v1_lt_v2((&"2024-01-31", &"2024-02-01")).is_ok());
+        // `None` sorts before every value.
+        assert!(Validator::v1_gt_v2((&Some(0), &None)).is_ok());
+        assert!(Validator::v1_le_v2((&None, &Some(0))).is_ok());
+        assert!(Validator::v1_ge_v2((&None::<i32>, &None)).is_ok());
+        assert!(matches!(
+            Validator::v1_gt_v2((&1, &2)).map_err(|e| e.compared_to(&["start"])),
+            Err(ValidationError::V1LEV2 { other: Some(other) }) if other == "start"
+        ));
+    }
+
+    #[test]
+    fn optional_v2_gt_v1() {
+        assert!(Validator::optional_v2_gt_v1((&1, &Some(2))).is_ok());
+        assert!(Validator::optional_v2_gt_v1((&1, &Some(1))).is_ok());
+        assert!(matches!(
+            Validator::optional_v2_gt_v1((&2, &Some(1))),
+            Err(ValidationError::V1GTV2 { other: None })
+        ));
+        assert!(Validator::optional_v2_gt_v1((&1, &None)).is_ok());
+    }
+
+    #[test]
+    fn lengths() {
+        let value = "пароль".to_string();
+        assert!(Validator::length_at_least((&value, 5)).is_ok());
+        assert!(Validator::length_at_least((&value, 6)).is_ok());
+        assert!(matches!(
+            Validator::length_at_least((&value, 7)),
+            Err(ValidationError::StringTooShort { min: 7, actual: 6 })
+        ));
+        assert!(Validator::length_at_most((&value, 7)).is_ok());
+        assert!(Validator::length_at_most((&value, 6)).is_ok());
+        assert!(matches!(
+            Validator::length_at_most((&value, 5)),
+            Err(ValidationError::StringTooLong { max: 5, actual: 6 })
+        ));
+        assert!(Validator::length_in_range((&value, 6, 6)).is_ok());
+        assert!(Validator::length_in_range((&value, 5, 7)).is_ok());
+        assert!(matches!(
+            Validator::length_in_range((&value, 7, 8)),
+            Err(ValidationError::StringTooShort { min: 7, actual: 6 })
+        ));
+        assert!(matches!(
+            Validator::length_in_range((&value, 4, 5)),
+            Err(ValidationError::StringTooLong { max: 5, actual: 6 })
+        ));
+        assert!(Validator::length_at_most((&String::new(), 0)).is_ok());
+        assert!(Validator::length_at_least((&String::new(), 1)).is_err());
+    }
+
+    #[test]
+    fn equality_comparisons() {
+        assert!(Validator::v1_eq_v2((&1, &1)).is_ok());
+        assert!(matches!(
+            Validator::v1_eq_v2((&1, &2)),
+            Err(ValidationError::V1NEV2 { .. })
+        ));
+        assert!(Validator::v1_ne_v2((&-1, &1)).is_ok());
+        assert!(matches!(
+            Validator::v1_ne_v2((&1, &1)),
+            Err(ValidationError::V1EQV2 { .. })
+        ));
+    }
+
+    #[test]
+    fn in_range() {
+        assert!(Validator::in_range((&-5, -5, 5)).is_ok());
+        assert!(Validator::in_range((&5, -5, 5)).is_ok());
+        assert!(matches!(
+            Validator::in_range((&-6, -5, 5)),
+            Err(ValidationError::NumberTooSmall { min, actual }) if min == -5.0 && actual == -6.0
+        ));
+        assert!(matches!(
+            Validator::in_range((&5.5, -5.0, 5.0)),
+            Err(ValidationError::NumberTooLarge { max, actual }) if max == 5.0 && actual == 5.5
+        ));
+        // 0.30000000000000004.
+        assert!(Validator::in_range((&(0.1 + 0.2), 0.0, 0.3)).is_err());
+        // NaN compares to nothing, `finite` rejects it.
+        assert!(Validator::in_range((&f64::NAN, 0.0, 1.0)).is_ok());
+        assert!(Validator::in_range((&f64::INFINITY, 0.0, 1.0)).is_err());
+    }
+
+    #[test]
+    fn multiple_of() {
+        assert!(Validator::multiple_of((&15, 5)).is_ok());
+        assert!(Validator::multiple_of((&-15, 5)).is_ok());
+        assert!(Validator::multiple_of((&0, 5)).is_ok());
+        assert!(matches!(
+            Validator::multiple_of((&7, 5)),
+            Err(ValidationError::NotMultipleOf { step }) if step == 5.0
+        ));
+        assert!(Validator::multiple_of((&(0.1 + 0.2), 0.1)).is_ok());
+        assert!(Validator::multiple_of((&1.15, 0.05)).is_ok());
+        assert!(Validator::multiple_of((&1.17, 0.05)).is_err());
+        assert!(Validator::multiple_of((&5, 0)).is_err());
+        assert!(Validator::multiple_of((&f64::NAN, 1.0)).is_err());
+        assert!(Validator::multiple_of((&f64::INFINITY, 1.0)).is_err());
+    }
+
+    #[test]
+    fn finite() {
+        assert!(Validator::finite(&-1.5).is_ok());
+        assert!(Validator::finite(&i64::MIN).is_ok());
+        assert!(matches!(
+            Validator::finite(&f64::NAN),
+            Err(ValidationError::NumberNotFinite)
+        ));
+        assert!(Validator::finite(&f64::INFINITY).is_err());
+        assert!(Validator::finite(&f32::NEG_INFINITY).is_err());
+    }
+
+    #[test]
+    fn max_decimal_places() {
+        assert!(Validator::max_decimal_places((&12, 0)).is_ok());
+        assert!(Validator::max_decimal_places((&-1.25, 2)).is_ok());
+        assert!(matches!(
+            Validator::max_decimal_places((&-1.255, 2)),
+            Err(ValidationError::TooManyDecimalPlaces { max: 2, actual: 3 })
+        ));
+        assert!(Validator::max_decimal_places((&0.3, 1)).is_ok());
+        // The sum is sent as 0.30000000000000004, with its 17 decimal places.
+        assert!(matches!(
+            Validator::max_decimal_places((&(0.1 + 0.2), 2)),
+            Err(ValidationError::TooManyDecimalPlaces { max: 2, actual: 17 })
+        ));
+    }
+
+    #[test]
+    fn integer_ranges() {
+        assert!(Validator::fits_i32(&(i32::MIN as i64)).is_ok());
+        assert!(Validator::fits_i32(&-1).is_ok());
+        assert!(matches!(
+            Validator::fits_i32(&(i32::MAX as i64 + 1)),
+            Err(ValidationError::IntegerOutOfRange { min, max }) if min == i32::MIN as i64 && max == i32::MAX as i64
+        ));
+        assert!(Validator::fits_u32(&u32::MAX).is_ok());
+        assert!(Validator::fits_u32(&-1).is_err());
+        assert!(Validator::fits_u32(&(u32::MAX as u64 + 1)).is_err());
+        assert!(Validator::fits_u32(&u64::MAX).is_err());
+        assert!(Validator::fits_i64(&i64::MIN).is_ok());
+        assert!(Validator::fits_i64(&(i64::MAX as u64)).is_ok());
+        assert!(Validator::fits_i64(&(i64::MAX as u64 + 1)).is_err());
+        assert!(Validator::fits_i64(&u64::MAX).is_err());
+    }
+
+    #[test]
+    fn trimmed() {
+        assert!(Validator::trimmed("value").is_ok());
+        assert!(Validator::trimmed("two words").is_ok());
+        assert!(matches!(
+            Validator::trimmed(" value"),
+            Err(ValidationError::StringNotTrimmed)
+        ));
+        assert!(Validator::trimmed("value\n").is_err());
+    }
+
+    #[test]
+    fn matches() {
+        assert!(Validator::matches((&"AB-12".to_string(), r"^[A-Z]{2}-\d{2}$")).is_ok());
+        assert!(matches!(
+            Validator::matches((&"ab-12".to_string(), r"^[A-Z]{2}-\d{2}$")),
+            Err(ValidationError::PatternMismatch { pattern }) if pattern == r"^[A-Z]{2}-\d{2}$"
+        ));
+    }
+
+    #[test]
+    fn matches_invalid_pattern() {
+        assert!(matches!(
+            Validator::matches((&"value".to_string(), "a(b")),
+            Err(ValidationError::PatternInvalid { pattern, .. }) if pattern == "a(b"
+        ));
+    }
+
+    #[test]
+    fn one_of() {
+        let allowed = &["simple", "compound"];
+        assert!(Validator::one_of((&"simple".to_string(), allowed)).is_ok());
+        assert!(matches!(
+            Validator::one_of((&"Simple".to_string(), allowed)),
+            Err(ValidationError::NotOneOf { allowed }) if allowed == ["simple", "compound"]
+        ));
+    }
+
+    #[test]
+    fn url() {
+        assert!(Validator::url("https://example.com").is_ok());
+        assert!(Validator::url("http://localhost:8080/path?query=1#part").is_ok());
+        assert!(matches!(
+            Validator::url("example.com"),
+            Err(ValidationError::UrlInvalid)
+        ));
+        assert!(Validator::url("ftp://example.com").is_err());
+        assert!(Validator::url("https://exa mple.com").is_err());
+    }
+
+    #[test]
+    fn iso4217_currency() {
+        assert!(Validator::iso4217_currency("USD").is_ok());
+        assert!(Validator::iso4217_currency("RUB").is_ok());
+        assert!(matches!(
+            Validator::iso4217_currency("usd"),
+            Err(ValidationError::CurrencyCodeInvalid)
+        ));
+        assert!(Validator::iso4217_currency("ABC").is_err());
+        assert!(Validator::iso4217_currency("US").is_err());
+    }
+
+    #[test]
+    fn bcp47_language() {
+        for tag in ["en", "en-US", "ru-RU", "zh-Hant-TW", "es-419", "de-CH-1996"] {
+            assert!(Validator::bcp47_language(tag).is_ok(), "{}", tag);
+        }
+        assert!(matches!(
+            Validator::bcp47_language("english"),
+            Err(ValidationError::LanguageTagInvalid)
+        ));
+        assert!(Validator::bcp47_language("en_US").is_err());
+        assert!(Validator::bcp47_language("").is_err());
+    }
+
+    #[test]
+    fn iban() {
+        assert!(Validator::iban("GB82WEST12345698765432").is_ok());
+        assert!(Validator::iban("DE89 3704 0044 0532 0130 00").is_ok());
+        assert!(matches!(
+            Validator::iban("GB83WEST12345698765432"),
+            Err(ValidationError::IbanInvalid)
+        ));
+        assert!(Validator::iban("gb82west12345698765432").is_err());
+        assert!(Validator::iban("GB82").is_err());
+    }
+
+    #[test]
+    fn password() {
+        PasswordPolicy::moderate().install("validators_test");
+        let password = "Correct1horse".to_string();
+        assert!(Validator::password((&password, "validators_test")).is_ok());
+        assert!(matches!(
+            Validator::password((&"Short1".to_string(), "validators_test")),
+            Err(ValidationError::PasswordTooShort { min: 8, actual: 6 })
+        ));
+        assert!(Validator::password_unlike((
+            &password,
+            "validators_test",
+            &"alice@example.com".to_string()
+        ))
+        .is_ok());
+        assert!(matches!(
+            Validator::password_unlike((
+                &password,
+                "validators_test",
+                &"correct1horse@example.com".to_string()
+            )),
+            Err(ValidationError::PasswordTooSimilar { other: None })
+        ));
+    }
+
+    #[test]
+    fn password_with_unknown_policy() {
+        let password = "Correct1horse".to_string();
+        assert!(matches!(
+            Validator::password((&password, "unknown_validators_test")),
+            Err(ValidationError::UnknownPasswordPolicy { name }) if name == "unknown_validators_test"
+        ));
+        assert!(matches!(
+            Validator::password_unlike((&password, "unknown_validators_test", &String::new())),
+            Err(ValidationError::UnknownPasswordPolicy { .. })
+        ));
+    }
+}
@@ -0,0 +1,80 @@
+//! The validators of `DefaultValidations` for the pages that don't run `web_client`, so that
+//! their forms check the values with the code of the server. Build it with
+//! `wasm-pack build actix-surreal-starter/actix-surreal-starter-validators --target web
+//! --out-dir ../../ssg/static/static/wasm` and import `/static/wasm/actix_surreal_validators.js`.
+//!
+//! The errors are serialized as by the server, e.g. `{"code": "StringTooShort", "params": {...}}`,
+//! to be rendered with the same messages.
+use actix_surreal_types::validators::{DefaultValidations, ValidationError};
+use actix_surreal_types::PasswordPolicy;
+use wasm_bindgen::prelude::*;
+
+struct Validator;
+impl DefaultValidations for Validator {}
+
+fn serialized(result: Result<(), ValidationError>) -> Option<String> {
+    result
+        .err()
+        .map(|error| serde_json::to_string(&error).unwrap_or_default())
+}
+
+/// Runs the validator of a text value by its name, returning the JSON of the error if any.
+#[wasm_bindgen]
+pub fn validate(validator: &str, value: &str) -> Result<Option<String>, String> {
+    let value = value.to_string();
+    let result = match validator {
+        "not_empty" => Validator::not_empty(&value),
+        "email_format" => Validator::email_format(&value),
+        _ => return Err(format!("`{}` isn't a validator of text values", validator)),
+    };
+    Ok(serialized(result))
+}
+
+/// `length_in_range`, whose bounds [`validate`] can't take.
+#[wasm_bindgen]
+pub fn validate_length(value: &str, min: usize, max: usize) -> Option<String> {
+    serialized(Validator::length_in_range((&value.to_string(), min, max)))
+}
+
+fn installed(policy: &str) -> Result<(), String> {
+    match PasswordPolicy::get(policy) {
+        Some(_) => Ok(()),
+        None => Err(format!("no password policy is installed as `{}`", policy)),
+    }
+}
+
+/// `password` with the policy of `name`, installed by [`install_password_policy`].
+#[wasm_bindgen]
+pub fn validate_password(value: &str, policy: &str) -> Result<Option<String>, String> {
+    installed(policy)?;
+    Ok(serialized(Validator::password((
+        &value.to_string(),
+        policy,
+    ))))
+}
+
+/// `password_unlike` comparing the password to the value of `field`.
+#[wasm_bindgen]
+pub fn validate_password_unlike(
+    value: &str,
+    policy: &str,
+    other: &str,
+    field: &str,
+) -> Result<Option<String>, String> {
+    installed(policy)?;
+    let result = Validator::password_unlike((&value.to_string(), policy, &other.to_string()));
+    Ok(serialized(result.map_err(|_| {
+        ValidationError::PasswordTooSimilar {
+            other: Some(field.to_string()),
+        }
+    })))
+}
+
+/// Installs a policy from its JSON as listed by the server at `/password_policies`, the
+/// rules left out being the ones of `PasswordPolicy::default()`.
+#[wasm_bindgen]
+pub fn install_password_policy(name: &str, policy: &str) -> Result<(), String> {
+    let policy: PasswordPolicy = serde_json::from_str(policy).map_err(|e| e.to_string())?;
+    policy.install(name);
+    Ok(())
+}
@@ -0,0 +1,73 @@
+//! Compares formatting the CRUD queries on every request with reusing the prepared ones.
+//!
+//! Run with `cargo bench -p actix-surreal-starter --bench query_cache`.
+
+use actix_surreal_starter::query_builder::{EntityOptions, QueryBuilder};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::hint::black_box;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Instant;
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+const ITERATIONS: usize = 100_000;
+
+static QUERY_BUILDER: QueryBuilder = QueryBuilder::new(
+    "transactions",
+    &["account_id.user_id", "metadata_id.user_id"],
+    None,
+    EntityOptions::DEFAULT,
+);
+
+fn measure(name: &str, request: impl Fn()) {
+    let allocations_before = ALLOCATIONS.load(Ordering::Relaxed);
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        request();
+    }
+    let elapsed = start.elapsed();
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed) - allocations_before;
+    println!(
+        "{name}: {:.2} allocations/request, {:?}/request",
+        allocations as f64 / ITERATIONS as f64,
+        elapsed / ITERATIONS as u32,
+    );
+}
+
+fn main() {
+    QUERY_BUILDER
+        .prepared()
+        .expect("queries must pass the SurrealQL parser");
+
+    measure("formatted per request", || {
+        black_box(QUERY_BUILDER.insert().unwrap());
+        black_box(QUERY_BUILDER.select().unwrap());
+        black_box(QUERY_BUILDER.select_all().unwrap());
+        black_box(QUERY_BUILDER.update().unwrap());
+        black_box(QUERY_BUILDER.delete().unwrap());
+    });
+    measure("prepared", || {
+        let prepared = QUERY_BUILDER.prepared().unwrap();
+        black_box(prepared.insert.as_str());
+        black_box(prepared.select.as_str());
+        black_box(prepared.select_all.as_str());
+        black_box(prepared.update.as_str());
+        black_box(prepared.delete.as_str());
+    });
+}
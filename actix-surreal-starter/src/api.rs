@@ -1,6 +1,10 @@
+use crate::entity::ApiEntity;
+use actix_web::http::header;
+use actix_web::HttpRequest;
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
+use std::str::FromStr;
 use surrealdb::RecordId;
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -21,3 +25,53 @@ impl<T: DeserializeOwned> WithId<T> {
         }
     }
 }
+
+/// Deserializes the content of a record sent by a user, with the fields marked `[owner]` set
+/// to the user and the other fields the server fills in set to their placeholders. Clients can
+/// leave them out, and can't write records for someone else.
+pub fn owned_content<T: ApiEntity>(
+    content: serde_json::Value,
+    user_id: &RecordId,
+) -> Result<T, serde_json::Error> {
+    let mut content = content;
+    if let serde_json::Value::Object(map) = &mut content {
+        map.extend(T::server_placeholders());
+        for field in T::query_builder().options.owner_fields() {
+            map.insert(field.to_string(), serde_json::to_value(user_id)?);
+        }
+    }
+    serde_json::from_value(content)
+}
+
+/// Query string of the routes addressing a single record, e.g. `?id=accounts:abc`.
+#[derive(Debug, Deserialize)]
+pub struct IdQuery {
+    pub id: String,
+}
+
+impl IdQuery {
+    pub fn record_id(&self) -> Option<RecordId> {
+        RecordId::from_str(&self.id).ok()
+    }
+}
+
+/// Query string of the search routes, e.g. `?q=groceries`.
+#[derive(Debug, Deserialize)]
+pub struct SearchQuery {
+    pub q: String,
+}
+
+/// The version an update is based on, taken from the `If-Match` header (e.g. `"3"`)
+/// or else from the `version` field of the submitted content.
+pub fn requested_version(request: &HttpRequest, content: &serde_json::Value) -> Option<u64> {
+    match request.headers().get(header::IF_MATCH) {
+        Some(value) => value
+            .to_str()
+            .ok()?
+            .trim_start_matches("W/")
+            .trim_matches('"')
+            .parse()
+            .ok(),
+        None => content.get(crate::query_builder::VERSION_FIELD)?.as_u64(),
+    }
+}
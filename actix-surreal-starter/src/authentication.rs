@@ -2,6 +2,8 @@ use crate::session::{
     build_session_token_cookies, create_session, delete_session_from_db, delete_tokens,
     refresh_session,
 };
+use crate::user_db::forget_user_connection;
+use crate::validation::ValidationContext;
 use crate::{NamesConfig, QueriesConfig, SessionConfig, DB};
 use actix_surreal_types::{ClientError, Error, ServerError};
 use actix_web::cookie::Cookie;
@@ -23,6 +25,10 @@ pub trait LoginData {
     fn get_password_mut(&mut self) -> &mut String;
     fn get_password(&self) -> &String;
     fn get_login(&self) -> &String;
+    /// Rewrites the login into the form it is stored and looked up in, e.g. with
+    /// `normalize_email`, before `register` checks that it is free and before `login` looks it
+    /// up. Logins are kept as sent by default.
+    fn normalize_login(&mut self) {}
 }
 
 #[derive(Deserialize)]
@@ -35,8 +41,14 @@ pub async fn login(
     queries: Arc<QueriesConfig>,
     session_config: Arc<SessionConfig>,
 ) -> actix_surreal_types::ResponseResult {
-    let creds = creds.into_inner();
-    let user: Option<IdAndPassword> = get_id_and_password(&queries, &creds).await?;
+    let mut creds = creds.into_inner();
+    let login = creds.get_login().clone();
+    creds.normalize_login();
+    let mut user: Option<IdAndPassword> = get_id_and_password(&queries, creds.get_login()).await?;
+    // Users registered before their logins were normalized are stored as they typed them.
+    if user.is_none() && creds.get_login() != &login {
+        user = get_id_and_password(&queries, &login).await?;
+    }
     if let Some(id_and_password) = user {
         if validate_password(
             creds.get_password().as_str(),
@@ -52,11 +64,11 @@ pub async fn login(
 
 async fn get_id_and_password(
     queries_config: &QueriesConfig,
-    creds: &impl LoginData,
+    login: &str,
 ) -> Result<Option<IdAndPassword>, surrealdb::Error> {
     let mut response = DB
         .query(queries_config.get_user_id_and_password_by_login)
-        .bind(("login", creds.get_login().clone()))
+        .bind(("login", login.to_string()))
         .await?;
     Ok(response.take(0)?)
 }
@@ -68,6 +80,7 @@ pub async fn logout(
 ) -> actix_surreal_types::ResponseResult {
     match get_access_token(&http_request, &session_config) {
         Ok(access_token) => {
+            forget_user_connection(&access_token);
             delete_session_from_db(&queries_config, access_token).await?;
             Ok(respond_with_tokens_deletion(&session_config).await)
         }
@@ -88,11 +101,13 @@ where
     TUserdataError: Serialize,
 {
     let mut creds = creds.into_inner();
-    let validation_result = (register_config.validate)(&creds);
+    let validation_result =
+        (register_config.validate)(&creds, ValidationContext::anonymous()).await;
     if let Err(_) = validation_result {
         return Ok(HttpResponse::Ok().json(validation_result));
     }
-    if get_id_and_password(&queries_config, &creds)
+    creds.normalize_login();
+    if get_id_and_password(&queries_config, creds.get_login())
         .await?
         .is_some()
     {
@@ -118,6 +133,10 @@ pub async fn refresh(
     match refresh_token_cookie {
         None => Err(ClientError::NoRefreshToken.into()),
         Some(refresh_token_cookie) => {
+            // The connection of the replaced access token must not outlive it.
+            if let Ok(access_token) = get_access_token(&http_request, &session_config) {
+                forget_user_connection(&access_token);
+            }
             let session_tokens = refresh_session(
                 refresh_token_cookie.value().to_string(),
                 &queries_config,
@@ -168,7 +187,12 @@ async fn get_user_id(
 
 pub type BindQueryData<TUserdata> =
     Box<dyn Fn(Query<Client>, TUserdata) -> Query<Client> + Send + Sync>;
-pub type Validator<TUserdata, TUserdataError> = fn(&TUserdata) -> Result<(), TUserdataError>;
+/// Validates the data of the registering user, with the root connection in the context.
+pub type Validator<TUserdata, TUserdataError> =
+    for<'a> fn(
+        &'a TUserdata,
+        ValidationContext<'a>,
+    ) -> Pin<Box<dyn Future<Output = Result<(), TUserdataError>> + 'a>>;
 pub struct RegisterConfig<TQuery, TUserdata, TUserdataError>
 where
     TQuery: IntoQuery + Send + Sync,
@@ -206,10 +230,17 @@ where
 
 #[macro_export]
 macro_rules! build_register_config {
-    ($table_name:literal, |$ident:ident:$ty:ty|$ty_error:ty| { query_config: { $($db_field_name: literal: $value: expr),*$(,)? } validator: $validator:expr } ) => {
+    ($table_name:literal, |$ident:ident:$ty:ty|$ty_error:ty| { query_config: { $($db_field_name: literal: $value: expr),*$(,)? } validator $(($context:ident))?: $validator:expr } ) => {
         RegisterConfig::<String, $ty, $ty_error>::with_generated_query($table_name, vec![$($db_field_name,)*], Box::new(|query: Query<Client>, $ident:$ty| {
             query$(.bind(($db_field_name, $value)))*
-        }), |$ident| $validator)
+        }), |$ident, $crate::build_register_config!(@context $($context)?)| Box::pin(async move { $validator }))
+    };
+    // `validator(context)` names the `ValidationContext` for async validators.
+    (@context) => {
+        _
+    };
+    (@context $context:ident) => {
+        $context
     };
 }
 
@@ -0,0 +1,736 @@
+use crate::openapi::EntityDoc;
+use actix_surreal_types::validators::DEFAULT_VALIDATIONS;
+use serde_json::Value;
+
+/// Types accepted by the authentication endpoints, by the name of their entity.
+pub struct AuthEntities {
+    pub creds: &'static str,
+    pub register: &'static str,
+}
+
+/// Entities whose routes are registered and work: they have a table and ownership paths.
+fn routed(entities: &[EntityDoc]) -> impl Iterator<Item = &EntityDoc> {
+    entities.iter().filter(|entity| {
+        !entity.query_builder.options.no_table && !entity.query_builder.paths.is_empty()
+    })
+}
+
+/// Owner fields are set by the server, so the clients may leave them out.
+fn is_owner(entity: &EntityDoc, field: &str) -> bool {
+    entity
+        .query_builder
+        .options
+        .owner_fields()
+        .any(|owner| owner == field)
+}
+
+/// Fields filled in by the server, the owner fields among them, which the clients may leave out
+/// and don't validate.
+fn is_server_filled(entity: &EntityDoc, field: &str) -> bool {
+    entity
+        .query_builder
+        .options
+        .server_filled()
+        .any(|server_field| server_field == field)
+}
+
+fn field_name(entity_field: &str) -> &str {
+    entity_field.trim_start_matches("r#")
+}
+
+/// The Rust type of a field as it reads in the client, which has no `surrealdb` dependency.
+fn client_rust_type(rust_type: &str) -> String {
+    rust_type
+        .replace("surrealdb::RecordId", "RecordId")
+        .replace("surrealdb::Datetime", "DateTime<Utc>")
+        .replace("chrono::", "")
+        .replace(' ', "")
+}
+
+/// The arguments of a validator as declared, split at the commas outside of brackets and quotes.
+fn split_args(args: &str) -> Vec<&str> {
+    let (mut depth, mut quoted, mut start) = (0, false, 0);
+    let mut split = Vec::new();
+    for (i, c) in args.char_indices() {
+        match c {
+            '"' if !args[..i].ends_with('\\') => quoted = !quoted,
+            '(' | '[' if !quoted => depth += 1,
+            ')' | ']' if !quoted => depth -= 1,
+            ',' if !quoted && depth == 0 => {
+                split.push(&args[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    split.push(&args[start..]);
+    split
+        .into_iter()
+        .map(str::trim)
+        .filter(|arg| !arg.is_empty())
+        .collect()
+}
+
+/// The name and the arguments of a validator as declared, e.g. `v1_gt_v2(end_date)` or
+/// `one_of(["a", "b"])`.
+fn parse_validator(declared: &str) -> (&str, Vec<&str>) {
+    match declared.split_once('(') {
+        Some((name, args)) => {
+            let args = args.trim_end().strip_suffix(')').unwrap_or(args);
+            (name.trim(), split_args(args))
+        }
+        None => (declared.trim(), Vec::new()),
+    }
+}
+
+/// The argument of a validator taking `values`, which take a tuple when there are several.
+fn tuple(values: &[String]) -> String {
+    match values {
+        [value] => value.clone(),
+        values => format!("({})", values.join(", ")),
+    }
+}
+
+/// A check pushing the error of `call` with `push`, e.g. `result.name.push(e.into())`.
+fn rust_check(call: &str, push: &str, indent: &str) -> String {
+    format!(
+        "{0}if let Err(e) = {1} {{\n{0}    {2};\n{0}    erronous = true;\n{0}}}\n",
+        indent, call, push
+    )
+}
+
+/// `validate()` of an entity in the Rust client, running the validators of `DefaultValidations`
+/// from `actix-surreal-types`, the same code the server runs. The others only run on the server.
+fn rust_validate(entity: &EntityDoc) -> String {
+    let is_field = |arg: &str| entity.fields.iter().any(|field| field.name == arg);
+    let argument = |arg: &str| match (is_field(arg), arg.starts_with('[')) {
+        (true, _) => format!("&self.{}", arg),
+        (false, true) => format!("&{}", arg),
+        (false, false) => arg.to_string(),
+    };
+    // The call of a validator of `DefaultValidations` on `value` and the arguments, if it is one.
+    let default_call = |name: &str, value: Option<String>, args: &[&str]| {
+        if !DEFAULT_VALIDATIONS.contains(&name)
+            || args.iter().any(|arg| is_server_filled(entity, arg))
+        {
+            return None;
+        }
+        // Only the validators of fields tell the fields they were compared to.
+        let compares = value.is_some();
+        let mut values: Vec<String> = value.into_iter().collect();
+        values.extend(args.iter().map(|arg| argument(arg)));
+        let mut call = format!(
+            "<Validator as DefaultValidations>::{}({})",
+            name,
+            tuple(&values)
+        );
+        let compared: Vec<String> = args
+            .iter()
+            .filter(|arg| is_field(arg))
+            .map(|arg| format!("{:?}", field_name(arg)))
+            .collect();
+        if compares && !compared.is_empty() {
+            call += &format!(
+                ".map_err(|e| actix_surreal_types::validators::ComparedFields::compared_to(e, &[{}]))",
+                compared.join(", ")
+            );
+        }
+        Some(call)
+    };
+    let mut checks = String::new();
+    for field in &entity.fields {
+        if is_server_filled(entity, field.name) {
+            continue;
+        }
+        for declared in field.validators {
+            let (name, args) = parse_validator(declared);
+            let push = format!("result.{}.push(e.into())", field.name);
+            match name {
+                "nested" => {
+                    checks += &rust_check(
+                        &format!("self.{}.validate()", field.name),
+                        &format!("result.{} = Some(e)", field.name),
+                        "        ",
+                    )
+                }
+                // `each(...)`, with the validators of the items optionally listed in brackets.
+                "each" => {
+                    let inner = match args.as_slice() {
+                        [list] if list.starts_with('[') => split_args(&list[1..list.len() - 1]),
+                        _ => args,
+                    };
+                    let push = format!(
+                        "result.{}.push(actix_surreal_types::validators::ItemError::at_index(e, index).into())",
+                        field.name
+                    );
+                    let item_checks: String = inner
+                        .iter()
+                        .map(|declared| parse_validator(declared))
+                        .filter_map(|(name, args)| {
+                            default_call(name, Some("item".to_string()), &args)
+                        })
+                        .map(|call| rust_check(&call, &push, "            "))
+                        .collect();
+                    if !item_checks.is_empty() {
+                        checks += &format!(
+                            "        for (index, item) in self.{}.iter().enumerate() {{\n{}        }}\n",
+                            field.name, item_checks
+                        );
+                    }
+                }
+                name => {
+                    if let Some(call) =
+                        default_call(name, Some(format!("&self.{}", field.name)), &args)
+                    {
+                        checks += &rust_check(&call, &push, "        ");
+                    }
+                }
+            }
+        }
+    }
+    // Validators of the whole entity without listed fields take the server type of the entity.
+    for declared in entity.validators {
+        let (name, args) = parse_validator(declared);
+        if args.is_empty() {
+            continue;
+        }
+        if let Some(call) = default_call(name, None, &args) {
+            checks += &rust_check(&call, "result._entity.push(e.into())", "        ");
+        }
+    }
+    let mut validate = format!(
+        "\nimpl {} {{\n    /// The validations the server runs that don't need the server.\n    pub fn validate(&self) -> Result<(), {}> {{\n",
+        entity.name, entity.error_name
+    );
+    if checks.is_empty() {
+        return validate + "        Ok(())\n    }\n}\n";
+    }
+    validate += "        let mut erronous = false;\n";
+    validate += &format!("        let mut result = {} {{\n", entity.error_name);
+    for field in &entity.fields {
+        let empty = if field.nested_error.is_some() {
+            "None"
+        } else {
+            "Vec::new()"
+        };
+        validate += &format!("            {}: {},\n", field.name, empty);
+    }
+    validate += "            _entity: Vec::new(),\n        };\n";
+    validate += &checks;
+    validate + "        match erronous {\n            true => Err(result),\n            false => Ok(()),\n        }\n    }\n}\n"
+}
+
+/// A Rust module for `web_client`, calling the API with `gloo-net`.
+///
+/// The entity routes selecting a single record take the id as a `GET` body, which browsers
+/// refuse to send, so they are left out.
+pub fn rust_client(entities: &[EntityDoc], auth: &AuthEntities) -> String {
+    let mut client = RUST_PRELUDE.to_string();
+    for entity in entities {
+        client += "\n#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]\n";
+        client += &format!("pub struct {} {{\n", entity.name);
+        for field in &entity.fields {
+            if is_owner(entity, field.name) {
+                client += "    /// The user of the session, set by the server.\n";
+                client += "    #[serde(default, skip_serializing_if = \"Option::is_none\")]\n";
+                client += &format!("    pub {}: Option<RecordId>,\n", field.name);
+                continue;
+            }
+            if is_server_filled(entity, field.name) {
+                let rust_type = client_rust_type(field.rust_type);
+                client += "    /// Set by the server.\n";
+                client += "    #[serde(default, skip_serializing_if = \"Option::is_none\")]\n";
+                client += &match rust_type.starts_with("Option<") {
+                    true => format!("    pub {}: {},\n", field.name, rust_type),
+                    false => format!("    pub {}: Option<{}>,\n", field.name, rust_type),
+                };
+                continue;
+            }
+            if !field.required {
+                client += "    #[serde(default)]\n";
+            }
+            client += &format!(
+                "    pub {}: {},\n",
+                field.name,
+                client_rust_type(field.rust_type)
+            );
+        }
+        if entity.query_builder.options.versioned {
+            client += "    #[serde(default, skip_serializing_if = \"Option::is_none\")]\n";
+            client += "    pub version: Option<u64>,\n";
+        }
+        client += "}\n";
+        client += "\n#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]\n";
+        client += &format!("pub struct {} {{\n", entity.error_name);
+        for field in &entity.fields {
+            let errors = match field.nested_error {
+                Some(nested) => format!("Option<{}>", nested),
+                None => "Vec<ValidationError>".to_string(),
+            };
+            client += &format!("    pub {}: {},\n", field.name, errors);
+        }
+        client += "    pub _entity: Vec<ValidationError>,\n";
+        client += "}\n";
+        client += &rust_validate(entity);
+    }
+    for entity in routed(entities) {
+        client += &rust_entity_module(entity);
+    }
+    let register_error = entities
+        .iter()
+        .find(|entity| entity.name == auth.register)
+        .map(|entity| entity.error_name)
+        .unwrap_or("serde_json::Value");
+    client += &format!(
+        r#"
+/// The authentication endpoints. Successful calls set or remove the session cookies.
+pub mod auth {{
+    use super::*;
+
+    /// Why a registration was refused.
+    #[derive(Debug, Deserialize)]
+    #[serde(untagged)]
+    pub enum RegisterFailure {{
+        Client(ClientError),
+        Invalid({register_error}),
+    }}
+
+    pub async fn login(creds: &{creds}) -> Result<Result<(), ClientError>, ApiError> {{
+        session_result(send_json(Request::post("/login"), creds).await?).await
+    }}
+
+    pub async fn register(data: &{register}) -> Result<Result<(), RegisterFailure>, ApiError> {{
+        session_result(send_json(Request::post("/register"), data).await?).await
+    }}
+
+    pub async fn logout() -> Result<Result<(), ClientError>, ApiError> {{
+        session_result(send(Request::post("/logout")).await?).await
+    }}
+
+    pub async fn refresh() -> Result<Result<(), ClientError>, ApiError> {{
+        session_result(send(Request::post("/refresh")).await?).await
+    }}
+
+    /// Data of the user of the session, without the password.
+    pub async fn me() -> Result<Result<serde_json::Value, ClientError>, ApiError> {{
+        let value: serde_json::Value = send(Request::get("/me")).await?.json().await?;
+        Ok(match serde_json::from_value::<Result<(), ClientError>>(value.clone()) {{
+            Ok(Err(error)) => Err(error),
+            _ => Ok(value),
+        }})
+    }}
+}}
+"#,
+        creds = auth.creds,
+        register = auth.register,
+        register_error = register_error,
+    );
+    client
+}
+
+fn rust_entity_module(entity: &EntityDoc) -> String {
+    let options = &entity.query_builder.options;
+    let table = entity.query_builder.table_name;
+    let (name, error) = (entity.name, entity.error_name);
+    let mut module = format!(
+        r#"
+/// Routes of the `{table}` table.
+pub mod {table} {{
+    use super::*;
+
+    pub const ADDRESS: &str = "/api/{table}";
+
+    pub async fn all() -> Result<Vec<{name}>, ApiError> {{
+        Ok(send(Request::get("/api/{table}/all")).await?.json().await?)
+    }}
+
+    pub async fn insert(value: &{name}) -> Result<RecordId, ApiError> {{
+        Ok(send_json(Request::post(ADDRESS), value).await?.json().await?)
+    }}
+
+    pub async fn update(id: &RecordId, data: &{name}) -> Result<(), ApiError> {{
+        send_json(Request::put(ADDRESS), &WithId {{ id, data }}).await?;
+        Ok(())
+    }}
+
+    pub async fn delete(id: &RecordId) -> Result<(), ApiError> {{
+        send_json(Request::delete(ADDRESS), id).await?;
+        Ok(())
+    }}
+
+    pub async fn insert_batch(values: &[{name}]) -> Result<BatchResult<RecordId, {error}>, ApiError> {{
+        Ok(send_json(Request::post("/api/{table}/batch"), values).await?.json().await?)
+    }}
+
+    pub async fn update_batch(values: &[(RecordId, {name})]) -> Result<BatchResult<(), {error}>, ApiError> {{
+        let values: Vec<WithId<{name}>> = values.iter().map(|(id, data)| WithId {{ id, data }}).collect();
+        Ok(send_json(Request::put("/api/{table}/batch"), &values).await?.json().await?)
+    }}
+
+    pub async fn delete_batch(ids: &[RecordId]) -> Result<BatchResult<(), ()>, ApiError> {{
+        Ok(send_json(Request::delete("/api/{table}/batch"), ids).await?.json().await?)
+    }}
+"#,
+    );
+    if options.soft_delete {
+        module += &format!(
+            r#"
+    pub async fn restore(id: &RecordId) -> Result<(), ApiError> {{
+        send_json(Request::post("/api/{table}/restore"), id).await?;
+        Ok(())
+    }}
+
+    pub async fn trash() -> Result<Vec<{name}>, ApiError> {{
+        Ok(send(Request::get("/api/{table}/trash")).await?.json().await?)
+    }}
+"#,
+        );
+    }
+    if options.audit {
+        module += &format!(
+            r#"
+    /// The changes of the record, oldest first.
+    pub async fn history(id: &str) -> Result<Vec<serde_json::Value>, ApiError> {{
+        Ok(send(Request::get("/api/{table}/history").query([("id", id)])).await?.json().await?)
+    }}
+"#,
+        );
+    }
+    if !options.searchable.is_empty() {
+        module += &format!(
+            r#"
+    /// The matching records with their `score`, best matches first.
+    pub async fn search(q: &str) -> Result<Vec<serde_json::Value>, ApiError> {{
+        Ok(send(Request::get("/api/{table}/search").query([("q", q)])).await?.json().await?)
+    }}
+"#,
+        );
+    }
+    for aggregation in options.aggregations {
+        module += &format!(
+            r#"
+    pub async fn {0}() -> Result<Vec<serde_json::Value>, ApiError> {{
+        Ok(send(Request::get("/api/{table}/aggregate/{0}")).await?.json().await?)
+    }}
+"#,
+            aggregation.name,
+        );
+    }
+    module + "}\n"
+}
+
+const RUST_PRELUDE: &str = r#"//! Client of the API, generated from the entities of the server by
+//! `cargo run -p server --bin generate_clients`. Regenerate it instead of editing it.
+// The errors of `validate()` have the layout of the errors of the server.
+#![allow(dead_code, clippy::result_large_err)]
+
+use actix_surreal_types::validators::DefaultValidations;
+use actix_surreal_types::ClientError;
+use chrono::{DateTime, Utc};
+use gloo_net::http::{RequestBuilder, Response};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use web_sys::RequestCredentials;
+
+pub use gloo_net::http::Request;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordId {
+    pub tb: String,
+    /// The key by kind, e.g. `{"String": "abc"}`.
+    pub id: serde_json::Value,
+}
+
+/// A validation error of the server, e.g. `{"code": "StringTooShort", "params": {"min": 8}}`,
+/// rendered by `actix_surreal_types::MessageCatalog`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ValidationError {
+    pub code: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub params: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+impl From<actix_surreal_types::validators::ValidationError> for ValidationError {
+    fn from(error: actix_surreal_types::validators::ValidationError) -> Self {
+        let value = serde_json::to_value(&error).unwrap_or_default();
+        serde_json::from_value(value).unwrap_or_else(|_| Self { code: error.to_string(), params: None })
+    }
+}
+
+/// Runs the validators shared with the server, see `validate()` of the entities.
+struct Validator;
+impl DefaultValidations for Validator {}
+
+/// Installs the password policies of the server, which the `password` validators of
+/// `validate()` check against. Until then, they fail with `UnknownPasswordPolicy`.
+pub async fn install_password_policies() -> Result<(), ApiError> {
+    let policies: std::collections::HashMap<String, actix_surreal_types::PasswordPolicy> =
+        send(Request::get("/password_policies")).await?.json().await?;
+    for (name, policy) in policies {
+        policy.install(&name);
+    }
+    Ok(())
+}
+
+#[derive(Debug)]
+pub enum ApiError {
+    Net(gloo_net::Error),
+    /// The status and the body of an unsuccessful response.
+    Status(u16, String),
+}
+
+impl From<gloo_net::Error> for ApiError {
+    fn from(value: gloo_net::Error) -> Self {
+        Self::Net(value)
+    }
+}
+
+/// The reason a single item of a batch was not written.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub enum BatchItemError<E> {
+    Invalid(E),
+    Unauthorized,
+    /// The item itself is fine, but other items of the batch were rejected.
+    RolledBack,
+}
+
+pub type BatchResult<T, E> = Vec<Result<T, BatchItemError<E>>>;
+
+#[derive(Serialize)]
+struct WithId<'a, T> {
+    id: &'a RecordId,
+    data: &'a T,
+}
+
+async fn send(request: RequestBuilder) -> Result<Response, ApiError> {
+    checked(request.credentials(RequestCredentials::Include).send().await?).await
+}
+
+async fn send_json(request: RequestBuilder, body: &(impl Serialize + ?Sized)) -> Result<Response, ApiError> {
+    checked(request.credentials(RequestCredentials::Include).json(body)?.send().await?).await
+}
+
+async fn checked(response: Response) -> Result<Response, ApiError> {
+    match response.ok() {
+        true => Ok(response),
+        false => Err(ApiError::Status(response.status(), response.text().await?)),
+    }
+}
+
+/// Successful authentication calls respond with an empty body, failed ones with `{"Err": ...}`.
+async fn session_result<E: DeserializeOwned>(response: Response) -> Result<Result<(), E>, ApiError> {
+    let body = response.text().await?;
+    if body.is_empty() {
+        return Ok(Ok(()));
+    }
+    serde_json::from_str(&body).map_err(|e| ApiError::Status(response.status(), e.to_string()))
+}
+"#;
+
+/// The TypeScript type of a JSON Schema of [`crate::openapi::OpenApiType`].
+fn typescript_type(schema: &Value) -> String {
+    if let Some(reference) = schema["$ref"].as_str() {
+        return reference
+            .rsplit('/')
+            .next()
+            .unwrap_or("unknown")
+            .to_string();
+    }
+    if let Some(variants) = schema["anyOf"].as_array() {
+        return variants
+            .iter()
+            .map(typescript_type)
+            .collect::<Vec<_>>()
+            .join(" | ");
+    }
+    match schema["type"].as_str() {
+        Some("string") => "string".to_string(),
+        Some("integer") | Some("number") => "number".to_string(),
+        Some("boolean") => "boolean".to_string(),
+        Some("null") => "null".to_string(),
+        Some("array") => match typescript_type(&schema["items"]) {
+            item if item.contains(' ') => format!("({})[]", item),
+            item => format!("{}[]", item),
+        },
+        _ => "unknown".to_string(),
+    }
+}
+
+/// A TypeScript module for the static pages, calling the API with `fetch`.
+/// Leaves out the same routes as [`rust_client`].
+pub fn typescript_client(entities: &[EntityDoc], auth: &AuthEntities) -> String {
+    let mut client = TYPESCRIPT_PRELUDE.to_string();
+    for entity in entities {
+        client += &format!("\nexport interface {} {{\n", entity.name);
+        for field in &entity.fields {
+            client += &format!(
+                "    {}{}: {};\n",
+                field_name(field.name),
+                if field.required && !is_server_filled(entity, field.name) {
+                    ""
+                } else {
+                    "?"
+                },
+                typescript_type(&field.schema)
+            );
+        }
+        if entity.query_builder.options.versioned {
+            client += "    version?: number;\n";
+        }
+        client += "}\n";
+        client += &format!("\nexport interface {} {{\n", entity.error_name);
+        for field in &entity.fields {
+            let errors = match field.nested_error {
+                Some(nested) => format!("{} | null", nested),
+                None => "ValidationError[]".to_string(),
+            };
+            client += &format!("    {}: {};\n", field_name(field.name), errors);
+        }
+        client += "    _entity: ValidationError[];\n";
+        client += "}\n";
+    }
+    for entity in routed(entities) {
+        client += &typescript_entity_object(entity);
+    }
+    let register_error = entities
+        .iter()
+        .find(|entity| entity.name == auth.register)
+        .map(|entity| entity.error_name)
+        .unwrap_or("unknown");
+    client += &format!(
+        r#"
+/** The authentication endpoints. Successful calls set or remove the session cookies and resolve to `undefined`. */
+export const auth = {{
+    login: async (creds: {creds}): Promise<ClientError | undefined> =>
+        sessionResult(await send("POST", "/login", creds)),
+    register: async (data: {register}): Promise<ClientError | {register_error} | undefined> =>
+        sessionResult(await send("POST", "/register", data)),
+    logout: async (): Promise<ClientError | undefined> => sessionResult(await send("POST", "/logout")),
+    refresh: async (): Promise<ClientError | undefined> => sessionResult(await send("POST", "/refresh")),
+    /** Data of the user of the session, without the password. */
+    me: async (): Promise<Omit<{register}, "password"> | {{ Err: ClientError }}> => (await send("GET", "/me")).json(),
+}};
+"#,
+        creds = auth.creds,
+        register = auth.register,
+        register_error = register_error,
+    );
+    client
+}
+
+fn typescript_entity_object(entity: &EntityDoc) -> String {
+    let options = &entity.query_builder.options;
+    let table = entity.query_builder.table_name;
+    let (name, error) = (entity.name, entity.error_name);
+    let mut object = format!(
+        r#"
+/** Routes of the `{table}` table. */
+export const {table} = {{
+    address: "/api/{table}",
+    all: async (): Promise<{name}[]> => (await send("GET", "/api/{table}/all")).json(),
+    insert: async (value: {name}): Promise<RecordId> => (await send("POST", "/api/{table}", value)).json(),
+    update: async (id: RecordId, data: {name}): Promise<void> => {{
+        await send("PUT", "/api/{table}", {{ id, data }});
+    }},
+    delete: async (id: RecordId): Promise<void> => {{
+        await send("DELETE", "/api/{table}", id);
+    }},
+    insertBatch: async (values: {name}[]): Promise<BatchResult<RecordId, {error}>> =>
+        (await send("POST", "/api/{table}/batch", values)).json(),
+    updateBatch: async (values: {{ id: RecordId; data: {name} }}[]): Promise<BatchResult<null, {error}>> =>
+        (await send("PUT", "/api/{table}/batch", values)).json(),
+    deleteBatch: async (ids: RecordId[]): Promise<BatchResult<null, null>> =>
+        (await send("DELETE", "/api/{table}/batch", ids)).json(),
+"#,
+    );
+    if options.soft_delete {
+        object += &format!(
+            r#"    restore: async (id: RecordId): Promise<void> => {{
+        await send("POST", "/api/{table}/restore", id);
+    }},
+    trash: async (): Promise<{name}[]> => (await send("GET", "/api/{table}/trash")).json(),
+"#,
+        );
+    }
+    if options.audit {
+        object += &format!(
+            r#"    /** The changes of the record, oldest first. */
+    history: async (id: string): Promise<HistoryEntry[]> =>
+        (await send("GET", `/api/{table}/history?id=${{encodeURIComponent(id)}}`)).json(),
+"#,
+        );
+    }
+    if !options.searchable.is_empty() {
+        object += &format!(
+            r#"    /** The matching records with their `score`, best matches first. */
+    search: async (q: string): Promise<({name} & {{ score: number }})[]> =>
+        (await send("GET", `/api/{table}/search?q=${{encodeURIComponent(q)}}`)).json(),
+"#,
+        );
+    }
+    for aggregation in options.aggregations {
+        object += &format!(
+            "    {0}: async (): Promise<Record<string, unknown>[]> => (await send(\"GET\", \"/api/{1}/aggregate/{0}\")).json(),\n",
+            aggregation.name, table,
+        );
+    }
+    object + "};\n"
+}
+
+const TYPESCRIPT_PRELUDE: &str = r#"// Client of the API, generated from the entities of the server by
+// `cargo run -p server --bin generate_clients`. Regenerate it instead of editing it.
+
+export interface RecordId {
+    tb: string;
+    /** The key by kind, e.g. `{"String": "abc"}`. */
+    id: Record<string, unknown>;
+}
+
+/** A validation error of the server, e.g. `{"code": "StringTooShort", "params": {"min": 8}}`. */
+export interface ValidationError {
+    code: string;
+    params?: Record<string, unknown>;
+}
+
+export type ClientError = "NoAccessToken" | "NoRefreshToken" | "InvalidCredentials" | "EmailTaken" | "InvalidAccessToken";
+
+/** The reason a single item of a batch was not written. */
+export type BatchItemError<E> = "Unauthorized" | "RolledBack" | { Invalid: E };
+
+export type BatchResult<T, E> = ({ Ok: T } | { Err: BatchItemError<E> })[];
+
+export interface HistoryEntry {
+    record: RecordId;
+    action: "insert" | "update" | "delete" | "restore";
+    before: Record<string, unknown> | null;
+    after: Record<string, unknown> | null;
+    user_id: RecordId;
+    timestamp: string;
+}
+
+/** An unsuccessful response. */
+export class ApiError extends Error {
+    constructor(public status: number, public body: string) {
+        super(`${status}: ${body}`);
+    }
+}
+
+async function send(method: string, address: string, body?: unknown): Promise<Response> {
+    const response = await fetch(address, {
+        method,
+        credentials: "include",
+        headers: body === undefined ? {} : { "Content-Type": "application/json" },
+        body: body === undefined ? undefined : JSON.stringify(body),
+    });
+    if (!response.ok) {
+        throw new ApiError(response.status, await response.text());
+    }
+    return response;
+}
+
+/** Successful authentication calls respond with an empty body, failed ones with `{"Err": ...}`. */
+async function sessionResult<E>(response: Response): Promise<E | undefined> {
+    const body = await response.text();
+    return body === "" ? undefined : JSON.parse(body)["Err"];
+}
+"#;
@@ -121,6 +121,7 @@ env_names!({
     db_namespace: "DB_NAMESPACE",
     db_name: "DB_NAME",
     static_files_serving_config: "STATIC_FILES_SERVING_CONFIG",
+    breached_passwords: "BREACHED_PASSWORDS",
 });
 
 #[derive(Clone)]
@@ -1,12 +1,15 @@
-use crate::query_builder::{BuilderError, QueryBuilder};
+use crate::api::WithId;
+use crate::query_builder::{BuilderError, QueryBuilder, CONFLICT_ERR, RESTRICTED_ERR};
+use crate::user_db::UserDb;
 use crate::DB;
-use actix_web::ResponseError;
+use actix_web::http::StatusCode;
+use actix_web::{HttpResponse, ResponseError};
 use serde::de::DeserializeOwned;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::option::Option;
 use surrealdb::RecordId;
 use thiserror::Error;
-// OPTIMIZE: Could benefit from using pre-built queries (built during initialization of the server) for tables to not have to format the query each time at runtime.
+use tokio::time;
 // OPTIMIZE: Should consider reducing String clowning where possible.
 
 #[derive(Debug, Error)]
@@ -21,22 +24,158 @@ pub enum CrudError {
     MissingRecord(RecordId),
     #[error("Internal error: cannot build query. Must be constructed in deeper water: {0}")]
     QueryConstructionError(#[from] BuilderError),
+    #[error("Delete is restricted while records reference it through {0}")]
+    Restricted(String),
+    #[error("Not a valid record id: {0}")]
+    InvalidRecordId(String),
+    #[error("The record was changed since the stated version")]
+    Conflict(serde_json::Value),
+    #[error("Updates of versioned records must state the version they are based on")]
+    MissingVersion,
+    #[error("No aggregation is declared with the following name: {0}")]
+    UnknownAggregation(String),
+    #[error("Table {0} has no searchable fields")]
+    NotSearchable(&'static str),
+    #[error("Invalid content: {0}")]
+    InvalidContent(#[from] serde_json::Error),
 }
 
-impl ResponseError for CrudError {}
+impl ResponseError for CrudError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            CrudError::Restricted(_) => StatusCode::CONFLICT,
+            CrudError::InvalidRecordId(_) | CrudError::InvalidContent(_) => StatusCode::BAD_REQUEST,
+            CrudError::Conflict(_) => StatusCode::CONFLICT,
+            CrudError::MissingVersion => StatusCode::PRECONDITION_REQUIRED,
+            CrudError::UnknownAggregation(_) | CrudError::NotSearchable(_) => StatusCode::NOT_FOUND,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    /// A [`CrudError::Conflict`] responds with the current state of the record, so that
+    /// the client can merge its changes and retry.
+    fn error_response(&self) -> HttpResponse {
+        match self {
+            CrudError::Conflict(current) => HttpResponse::Conflict().json(current),
+            _ => HttpResponse::build(self.status_code()).body(self.to_string()),
+        }
+    }
+}
+
+/// Turns the error thrown by a blocked delete into [`CrudError::Restricted`].
+fn map_restricted(error: surrealdb::Error) -> CrudError {
+    let message = error.to_string();
+    match message.split_once(&format!("{}:", RESTRICTED_ERR)) {
+        Some((_, reference)) => CrudError::Restricted(
+            reference
+                .split(|c: char| c.is_whitespace() || c == '\'')
+                .next()
+                .unwrap_or_default()
+                .to_string(),
+        ),
+        None => CrudError::DbError(error),
+    }
+}
+
+fn is_conflict(error: &CrudError) -> bool {
+    matches!(error, CrudError::DbError(error) if error.to_string().contains(CONFLICT_ERR))
+}
+
+/// The reason a single item of a batch was not written.
+#[derive(Debug, Serialize)]
+pub enum BatchItemError<E> {
+    Invalid(E),
+    Unauthorized,
+    /// The item itself is fine, but other items of the batch were rejected.
+    RolledBack,
+}
+
+/// Per-item results of a batch operation, in the order of the input items.
+pub type BatchResult<T, E> = Vec<Result<T, BatchItemError<E>>>;
+
+#[derive(Deserialize)]
+struct BatchOutcome {
+    owned: Vec<bool>,
+    ids: Vec<RecordId>,
+}
+
+impl BatchOutcome {
+    fn into_results<T, E>(
+        self,
+        on_success: impl FnOnce(Vec<RecordId>) -> Vec<T>,
+    ) -> BatchResult<T, E> {
+        if self.owned.iter().all(|owned| *owned) {
+            return on_success(self.ids).into_iter().map(Ok).collect();
+        }
+        self.owned
+            .into_iter()
+            .map(|owned| match owned {
+                true => Err(BatchItemError::RolledBack),
+                false => Err(BatchItemError::Unauthorized),
+            })
+            .collect()
+    }
+}
+
+async fn reject_invalid<T, R, E>(
+    values: &[T],
+    validate: impl AsyncFn(&T) -> Result<(), E>,
+) -> Option<BatchResult<R, E>> {
+    let mut results: Vec<Result<(), E>> = Vec::with_capacity(values.len());
+    for value in values {
+        results.push(validate(value).await);
+    }
+    if results.iter().all(Result::is_ok) {
+        return None;
+    }
+    Some(
+        results
+            .into_iter()
+            .map(|result| match result {
+                Ok(()) => Err(BatchItemError::RolledBack),
+                Err(e) => Err(BatchItemError::Invalid(e)),
+            })
+            .collect(),
+    )
+}
+
+async fn run_batch<T>(
+    query: &str,
+    items: (&'static str, T),
+    user: &UserDb,
+) -> Result<BatchOutcome, CrudError>
+where
+    T: Serialize + 'static,
+{
+    let mut response = user
+        .db
+        .query(query)
+        .bind(items)
+        .bind(("user_id", user.user_id.clone()))
+        .await?
+        .check()
+        .map_err(map_restricted)?;
+    let last_statement = response.num_statements() - 1;
+    response
+        .take::<Option<BatchOutcome>>(last_statement)?
+        .ok_or(CrudError::DbResultError(
+            "Batch query did not return its outcome".to_string(),
+        ))
+}
 
 pub async fn insert<T>(
     value: T,
-    user_id: RecordId,
-    query_builder: QueryBuilder,
+    user: &UserDb,
+    query_builder: &QueryBuilder,
 ) -> Result<RecordId, CrudError>
 where
     T: Serialize + 'static,
 {
-    let id = DB
-        .query(query_builder.insert()?)
+    let id = user
+        .db
+        .query(query_builder.prepared()?.insert.as_str())
         .bind(("value", value))
-        .bind(("user_id", user_id))
+        .bind(("user_id", user.user_id.clone()))
         .await?
         .take::<Option<RecordId>>(0)?
         .ok_or(CrudError::MissingId)?;
@@ -45,51 +184,309 @@ where
 
 pub async fn select<T: DeserializeOwned>(
     id: RecordId,
-    user_id: RecordId,
-    query_builder: QueryBuilder,
+    user: &UserDb,
+    query_builder: &QueryBuilder,
 ) -> Result<T, CrudError> {
-    Ok(DB
-        .query(query_builder.select()?)
+    Ok(user
+        .db
+        .query(query_builder.prepared()?.select.as_str())
         .bind(("id", id.clone()))
-        .bind(("user_id", user_id))
+        .bind(("user_id", user.user_id.clone()))
         .await?
         .take::<Option<T>>(0)?
         .ok_or(CrudError::MissingRecord(id.clone()))?)
 }
 
+/// [`select`] returning the record as JSON, including the fields unknown to the entity type.
+async fn select_json(
+    id: RecordId,
+    user: &UserDb,
+    query_builder: &QueryBuilder,
+) -> Result<serde_json::Value, CrudError> {
+    match user
+        .db
+        .query(query_builder.prepared()?.select.as_str())
+        .bind(("id", id.clone()))
+        .bind(("user_id", user.user_id.clone()))
+        .await?
+        .take::<surrealdb::Value>(0)?
+        .into_inner()
+        .into_json()
+    {
+        serde_json::Value::Array(mut records) if !records.is_empty() => Ok(records.swap_remove(0)),
+        _ => Err(CrudError::MissingRecord(id)),
+    }
+}
+
 pub async fn select_all<T: DeserializeOwned>(
-    user_id: RecordId,
-    query_builder: QueryBuilder,
+    user: &UserDb,
+    query_builder: &QueryBuilder,
 ) -> Result<Vec<T>, CrudError> {
-    Ok(DB
-        .query(query_builder.select_all()?)
-        .bind(user_id)
+    Ok(user
+        .db
+        .query(query_builder.prepared()?.select_all.as_str())
+        .bind(("user_id", user.user_id.clone()))
         .await?
         .take::<Vec<T>>(0)?)
 }
 
+/// Replaces the content of the record. Versioned tables require the `version` the new
+/// content is based on, and fail with [`CrudError::Conflict`] if the record has changed since.
 pub async fn update(
     id: RecordId,
     content_to_update: serde_json::Value,
-    user_id: RecordId,
-    query_builder: QueryBuilder,
+    version: Option<u64>,
+    user: &UserDb,
+    query_builder: &QueryBuilder,
 ) -> Result<(), CrudError> {
-    DB.query(query_builder.update()?)
-        .bind(("user_id", user_id))
-        .bind(("id", id))
+    if query_builder.options.versioned && version.is_none() {
+        return Err(CrudError::MissingVersion);
+    }
+    let result = user
+        .db
+        .query(query_builder.prepared()?.update.as_str())
+        .bind(("user_id", user.user_id.clone()))
+        .bind(("id", id.clone()))
         .bind(("value", content_to_update))
-        .await?;
-    Ok(())
+        .bind(("version", version))
+        .await?
+        .check();
+    match result.map_err(CrudError::from) {
+        Err(error) if is_conflict(&error) => Err(CrudError::Conflict(
+            select_json(id, user, query_builder).await?,
+        )),
+        result => result.map(|_| ()),
+    }
 }
 
 pub async fn delete(
     id: RecordId,
-    user_id: RecordId,
-    query_builder: QueryBuilder,
+    user: &UserDb,
+    query_builder: &QueryBuilder,
+) -> Result<(), CrudError> {
+    user.db
+        .query(query_builder.prepared()?.delete.as_str())
+        .bind(("user_id", user.user_id.clone()))
+        .bind(("id", id))
+        .await?
+        .check()
+        .map_err(map_restricted)?;
+    Ok(())
+}
+
+pub async fn restore(
+    id: RecordId,
+    user: &UserDb,
+    query_builder: &QueryBuilder,
 ) -> Result<(), CrudError> {
-    DB.query(query_builder.delete()?)
-        .bind(("user_id", user_id))
+    user.db
+        .query(query_builder.prepared()?.restore.as_str())
+        .bind(("user_id", user.user_id.clone()))
         .bind(("id", id))
         .await?;
     Ok(())
 }
+
+pub async fn select_deleted<T: DeserializeOwned>(
+    user: &UserDb,
+    query_builder: &QueryBuilder,
+) -> Result<Vec<T>, CrudError> {
+    Ok(user
+        .db
+        .query(query_builder.prepared()?.select_deleted.as_str())
+        .bind(("user_id", user.user_id.clone()))
+        .await?
+        .take::<Vec<T>>(0)?)
+}
+
+/// Lists the recorded changes of the record `id`, oldest first.
+pub async fn select_history(
+    id: RecordId,
+    user: &UserDb,
+    query_builder: &QueryBuilder,
+) -> Result<serde_json::Value, CrudError> {
+    Ok(user
+        .db
+        .query(query_builder.prepared()?.select_history.as_str())
+        .bind(("user_id", user.user_id.clone()))
+        .bind(("id", id))
+        .await?
+        .take::<surrealdb::Value>(0)?
+        .into_inner()
+        .into_json())
+}
+
+/// Runs the aggregation declared under `name`, returning one object per group.
+pub async fn aggregate(
+    name: &str,
+    user: &UserDb,
+    query_builder: &QueryBuilder,
+) -> Result<serde_json::Value, CrudError> {
+    let query = query_builder
+        .prepared()?
+        .aggregations
+        .get(name)
+        .ok_or_else(|| CrudError::UnknownAggregation(name.to_string()))?;
+    Ok(user
+        .db
+        .query(query.as_str())
+        .bind(("user_id", user.user_id.clone()))
+        .await?
+        .take::<surrealdb::Value>(0)?
+        .into_inner()
+        .into_json())
+}
+
+/// Full-text search through the searchable fields of the records of the user.
+pub async fn search(
+    q: String,
+    user: &UserDb,
+    query_builder: &QueryBuilder,
+) -> Result<serde_json::Value, CrudError> {
+    let query = query_builder
+        .prepared()?
+        .search
+        .as_ref()
+        .ok_or(CrudError::NotSearchable(query_builder.table_name))?;
+    Ok(user
+        .db
+        .query(query.as_str())
+        .bind(("user_id", user.user_id.clone()))
+        .bind(("q", q))
+        .await?
+        .take::<surrealdb::Value>(0)?
+        .into_inner()
+        .into_json())
+}
+
+/// [`search`] through every searchable table, returning the matches by table name. Tables
+/// without ownership paths have no prepared queries and are skipped.
+pub async fn search_all(
+    q: String,
+    user: &UserDb,
+    query_builders: Vec<&'static QueryBuilder>,
+) -> Result<serde_json::Map<String, serde_json::Value>, CrudError> {
+    let mut results = serde_json::Map::new();
+    for query_builder in query_builders {
+        if query_builder.options.searchable.is_empty() || query_builder.paths.is_empty() {
+            continue;
+        }
+        results.insert(
+            query_builder.table_name.to_string(),
+            search(q.clone(), user, query_builder).await?,
+        );
+    }
+    Ok(results)
+}
+
+/// Creates the full-text indexes of every searchable table.
+pub async fn define_search_indexes(
+    query_builders: &[&'static QueryBuilder],
+) -> Result<(), CrudError> {
+    for query_builder in query_builders {
+        if let Some(query) = &query_builder.prepared()?.define_search {
+            DB.query(query.as_str()).await?.check()?;
+        }
+    }
+    Ok(())
+}
+
+/// Periodically removes the records that were soft-deleted longer than their table's
+/// `purge_after` ago.
+pub async fn purge_soft_deleted(query_builders: Vec<&'static QueryBuilder>) -> ! {
+    let mut interval = time::interval(time::Duration::from_secs(1800));
+
+    loop {
+        interval.tick().await;
+        for query_builder in &query_builders {
+            if let Ok(prepared) = query_builder.prepared() {
+                let _ = DB.query(prepared.purge_deleted.as_str()).await;
+            }
+        }
+    }
+}
+
+/// Inserts all of the `values` in a single transaction, or none of them if any item
+/// is invalid or not owned by the user.
+pub async fn insert_batch<T, E>(
+    values: Vec<T>,
+    user: &UserDb,
+    query_builder: &QueryBuilder,
+    validate: impl AsyncFn(&T) -> Result<(), E>,
+) -> Result<BatchResult<RecordId, E>, CrudError>
+where
+    T: Serialize + 'static,
+{
+    if values.is_empty() {
+        return Ok(Vec::new());
+    }
+    if let Some(rejected) = reject_invalid(&values, validate).await {
+        return Ok(rejected);
+    }
+    let outcome = run_batch(
+        query_builder.prepared()?.insert_batch.as_str(),
+        ("values", values),
+        user,
+    )
+    .await?;
+    Ok(outcome.into_results(|ids| ids))
+}
+
+/// Replaces the content of all of the records in a single transaction, or of none of them
+/// if any item is invalid or not owned by the user. For versioned tables, a stale item fails
+/// the whole batch with a [`CrudError::Conflict`] holding the current state of every record.
+pub async fn update_batch<T, E>(
+    values: Vec<WithId<T>>,
+    user: &UserDb,
+    query_builder: &QueryBuilder,
+    validate: impl AsyncFn(&WithId<T>) -> Result<(), E>,
+) -> Result<BatchResult<(), E>, CrudError>
+where
+    T: Serialize + 'static,
+{
+    if values.is_empty() {
+        return Ok(Vec::new());
+    }
+    if let Some(rejected) = reject_invalid(&values, validate).await {
+        return Ok(rejected);
+    }
+    let count = values.len();
+    let ids: Vec<RecordId> = values.iter().map(|value| value.id.clone()).collect();
+    let outcome = match run_batch(
+        query_builder.prepared()?.update_batch.as_str(),
+        ("values", values),
+        user,
+    )
+    .await
+    {
+        Err(error) if is_conflict(&error) => {
+            let mut current = Vec::with_capacity(ids.len());
+            for id in ids {
+                current.push(select_json(id, user, query_builder).await?);
+            }
+            return Err(CrudError::Conflict(serde_json::Value::Array(current)));
+        }
+        outcome => outcome?,
+    };
+    Ok(outcome.into_results(|_| vec![(); count]))
+}
+
+/// Deletes all of the records in a single transaction, or none of them if any is not
+/// owned by the user.
+pub async fn delete_batch(
+    ids: Vec<RecordId>,
+    user: &UserDb,
+    query_builder: &QueryBuilder,
+) -> Result<BatchResult<(), ()>, CrudError> {
+    if ids.is_empty() {
+        return Ok(Vec::new());
+    }
+    let count = ids.len();
+    let outcome = run_batch(
+        query_builder.prepared()?.delete_batch.as_str(),
+        ("ids", ids),
+        user,
+    )
+    .await?;
+    Ok(outcome.into_results(|_| vec![(); count]))
+}
@@ -0,0 +1,252 @@
+use crate::api::{owned_content, requested_version, IdQuery, SearchQuery, WithId};
+use crate::crud_ops::{self, CrudError};
+use crate::openapi::EntityDoc;
+use crate::query_builder::QueryBuilder;
+use crate::validation::ValidationContext;
+use crate::UserDb;
+use actix_web::{web, HttpRequest, HttpResponse};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::future::Future;
+
+/// An entity exposed through the generated CRUD routes, implemented by `#[derive(ApiEntity)]`.
+pub trait ApiEntity: Serialize + DeserializeOwned + Clone + 'static {
+    /// The validation errors of the entity, by field.
+    type Error: Serialize + 'static;
+
+    fn table_name() -> &'static str;
+    fn request_address() -> &'static str;
+    fn query_builder() -> &'static QueryBuilder;
+    /// `DEFINE` statements for the table.
+    fn schema() -> String;
+    fn openapi() -> EntityDoc;
+    /// Default values of the `readonly`, `server_default` and `computed` fields, whose types
+    /// must implement `Default`. They stand in for what the clients send, since the queries
+    /// leave these fields to the database.
+    fn server_placeholders() -> serde_json::Map<String, serde_json::Value>;
+    fn validate(&self) -> Result<(), Self::Error>;
+    /// [`ApiEntity::validate`] along with the `async` validators.
+    fn validate_async(
+        &self,
+        context: ValidationContext<'_>,
+    ) -> impl Future<Output = Result<(), Self::Error>>;
+}
+
+/// Registers the CRUD routes of the entity under its [`ApiEntity::request_address`],
+/// along with the ones enabled by its options.
+pub fn configure_entity<T: ApiEntity>(cfg: &mut web::ServiceConfig) {
+    let address = T::request_address();
+    let options = &T::query_builder().options;
+    cfg.route(&format!("{}/all", address), web::get().to(|user: UserDb| async move {
+        crud_ops::select_all::<T>(&user, T::query_builder()).await.map(web::Json)
+    }))
+    .route(address, web::get().to(|id: web::Json<surrealdb::RecordId>, user: UserDb| async move {
+        crud_ops::select::<T>(id.0, &user, T::query_builder()).await.map(web::Json)
+    }))
+    .route(address, web::post().to(|entity: web::Json<serde_json::Value>, user: UserDb| async move {
+        let entity: T = owned_content::<T>(entity.0, &user.user_id)?;
+        if let Err(e) = entity.validate_async(ValidationContext::of_user(&user)).await {
+            return Ok(HttpResponse::UnprocessableEntity().json(e));
+        }
+        Ok::<_, CrudError>(HttpResponse::Ok().json(crud_ops::insert(entity, &user, T::query_builder()).await?))
+    }))
+    .route(address, web::put().to(
+        |request: HttpRequest, entity: web::Json<WithId<serde_json::Value>>, user: UserDb| async move {
+            let version = requested_version(&request, &entity.data);
+            let WithId { id, data } = entity.0;
+            let data: T = owned_content::<T>(data, &user.user_id)?;
+            if let Err(e) = data.validate_async(ValidationContext::of_user(&user).updating(&id)).await {
+                return Ok(HttpResponse::UnprocessableEntity().json(e));
+            }
+            let data = serde_json::to_value(data)?;
+            crud_ops::update(id, data, version, &user, T::query_builder()).await?;
+            Ok::<_, CrudError>(HttpResponse::Ok().finish())
+        },
+    ))
+    .route(address, web::delete().to(|id: web::Json<surrealdb::RecordId>, user: UserDb| async move {
+        crud_ops::delete(id.0, &user, T::query_builder()).await
+    }))
+    .route(&format!("{}/batch", address), web::post().to(
+        |entities: web::Json<Vec<serde_json::Value>>, user: UserDb| async move {
+            let entities = entities
+                .0
+                .into_iter()
+                .map(|entity| owned_content::<T>(entity, &user.user_id))
+                .collect::<Result<Vec<_>, _>>()?;
+            let validate = async |entity: &T| entity.validate_async(ValidationContext::of_user(&user)).await;
+            crud_ops::insert_batch(entities, &user, T::query_builder(), validate).await.map(web::Json)
+        },
+    ))
+    .route(&format!("{}/batch", address), web::put().to(
+        |entities: web::Json<Vec<WithId<serde_json::Value>>>, user: UserDb| async move {
+            let entities = entities
+                .0
+                .into_iter()
+                .map(|entity| {
+                    Ok(WithId {
+                        id: entity.id,
+                        data: owned_content::<T>(entity.data, &user.user_id)?,
+                    })
+                })
+                .collect::<Result<Vec<_>, serde_json::Error>>()?;
+            let validate = async |entity: &WithId<T>| {
+                entity.data.validate_async(ValidationContext::of_user(&user).updating(&entity.id)).await
+            };
+            crud_ops::update_batch(entities, &user, T::query_builder(), validate).await.map(web::Json)
+        },
+    ))
+    .route(&format!("{}/batch", address), web::delete().to(
+        |ids: web::Json<Vec<surrealdb::RecordId>>, user: UserDb| async move {
+            crud_ops::delete_batch(ids.0, &user, T::query_builder()).await.map(web::Json)
+        },
+    ));
+    if options.soft_delete {
+        cfg.route(
+            &format!("{}/restore", address),
+            web::post().to(
+                |id: web::Json<surrealdb::RecordId>, user: UserDb| async move {
+                    crud_ops::restore(id.0, &user, T::query_builder()).await
+                },
+            ),
+        )
+        .route(
+            &format!("{}/trash", address),
+            web::get().to(|user: UserDb| async move {
+                crud_ops::select_deleted::<T>(&user, T::query_builder())
+                    .await
+                    .map(web::Json)
+            }),
+        );
+    }
+    if options.audit {
+        cfg.route(
+            &format!("{}/history", address),
+            web::get().to(|query: web::Query<IdQuery>, user: UserDb| async move {
+                let id = query
+                    .record_id()
+                    .ok_or_else(|| CrudError::InvalidRecordId(query.id.clone()))?;
+                crud_ops::select_history(id, &user, T::query_builder())
+                    .await
+                    .map(web::Json)
+            }),
+        );
+    }
+    if !options.searchable.is_empty() {
+        cfg.route(
+            &format!("{}/search", address),
+            web::get().to(|query: web::Query<SearchQuery>, user: UserDb| async move {
+                crud_ops::search(query.into_inner().q, &user, T::query_builder())
+                    .await
+                    .map(web::Json)
+            }),
+        );
+    }
+    if !options.aggregations.is_empty() {
+        cfg.route(
+            &format!("{}/aggregate/{{name}}", address),
+            web::get().to(|name: web::Path<String>, user: UserDb| async move {
+                crud_ops::aggregate(&name, &user, T::query_builder())
+                    .await
+                    .map(web::Json)
+            }),
+        );
+    }
+}
+
+/// Registers `/api/search`, searching the tables of all the `query_builders` at once.
+pub fn configure_search(
+    cfg: &mut web::ServiceConfig,
+    query_builders: fn() -> Vec<&'static QueryBuilder>,
+) {
+    cfg.route(
+        "/api/search",
+        web::get().to(
+            move |query: web::Query<SearchQuery>, user: UserDb| async move {
+                crud_ops::search_all(query.into_inner().q, &user, query_builders())
+                    .await
+                    .map(web::Json)
+            },
+        ),
+    );
+}
+
+#[derive(Clone, Copy)]
+struct RegisteredEntity {
+    query_builder: fn() -> &'static QueryBuilder,
+    schema: fn() -> String,
+    openapi: fn() -> EntityDoc,
+    configure: fn(&mut web::ServiceConfig),
+}
+
+/// The entities served by the application, assembled from the types deriving `ApiEntity`.
+///
+/// ```ignore
+/// static ENTITIES: LazyLock<EntityRegistry> =
+///     LazyLock::new(|| EntityRegistry::new().register::<Account>().register::<Tag>());
+/// ```
+#[derive(Clone, Default)]
+pub struct EntityRegistry {
+    entities: Vec<RegisteredEntity>,
+}
+
+impl EntityRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register<T: ApiEntity>(mut self) -> Self {
+        self.entities.push(RegisteredEntity {
+            query_builder: T::query_builder,
+            schema: T::schema,
+            openapi: T::openapi,
+            configure: configure_entity::<T>,
+        });
+        self
+    }
+
+    /// Query builders of all the entities, to be prepared by the server at startup.
+    pub fn query_builders(&self) -> Vec<&'static QueryBuilder> {
+        self.entities
+            .iter()
+            .map(|entity| (entity.query_builder)())
+            .collect()
+    }
+
+    /// `DEFINE` statements for the tables of all the entities.
+    pub fn schema(&self) -> String {
+        self.entities
+            .iter()
+            .filter(|entity| !(entity.query_builder)().options.no_table)
+            .map(|entity| (entity.schema)())
+            .collect()
+    }
+
+    /// Schemas of all the entities and the routes of their tables, for the OpenAPI document.
+    pub fn openapi(&self) -> Vec<EntityDoc> {
+        self.entities
+            .iter()
+            .map(|entity| (entity.openapi)())
+            .collect()
+    }
+
+    /// Registers `/api/search` and the routes of every entity with a table.
+    pub fn configure_endpoints(&self, cfg: &mut web::ServiceConfig) {
+        let query_builders = self.query_builders();
+        cfg.route(
+            "/api/search",
+            web::get().to(move |query: web::Query<SearchQuery>, user: UserDb| {
+                let query_builders = query_builders.clone();
+                async move {
+                    crud_ops::search_all(query.into_inner().q, &user, query_builders)
+                        .await
+                        .map(web::Json)
+                }
+            }),
+        );
+        for entity in &self.entities {
+            if !(entity.query_builder)().options.no_table {
+                (entity.configure)(cfg);
+            }
+        }
+    }
+}
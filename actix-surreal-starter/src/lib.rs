@@ -4,18 +4,30 @@ mod configuration;
 mod session;
 #[macro_use]
 mod macros;
+pub mod api;
 mod authentication;
-mod helper_implementations;
-mod server_address;
-mod server_starter;
+pub mod client_gen;
 pub mod crud_ops;
-pub mod api;
+pub mod entity;
+mod helper_implementations;
+pub mod live;
+pub mod openapi;
 pub mod pre_built;
 pub mod query_builder;
+pub mod schema;
+mod server_address;
+mod server_starter;
 pub mod static_files;
+mod user_db;
+pub mod validation;
 
 pub use crate::authentication::{LoginData, RegisterConfig, UserId};
+pub use crate::entity::{ApiEntity, EntityRegistry};
+pub use crate::user_db::{UserDb, USER_ACCESS};
+pub use crate::validation::ValidationContext;
+#[doc(hidden)]
+pub use actix_surreal_starter_macros::api_entities;
 pub use actix_surreal_types::*;
 pub use configuration::*;
-pub use proc_macros::error_type;
+pub use proc_macros::{error_type, ApiEntity};
 pub use server_starter::*;
@@ -0,0 +1,175 @@
+use crate::crud_ops::CrudError;
+use crate::query_builder::{QueryBuilder, DELETED_AT_FIELD};
+use crate::UserDb;
+use actix_web::rt::task::JoinHandle;
+use actix_web::{web, HttpRequest, HttpResponse};
+use actix_ws::{Message, Session};
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use surrealdb::Action;
+
+/// Path of the WebSocket endpoint streaming the changes of the declared entities.
+///
+/// The client sends `{"type": "subscribe", "table": "accounts"}` or
+/// `{"type": "unsubscribe", "table": "accounts"}` and receives a
+/// `{"type": "notification", "table", "action", "record"}` message for every created,
+/// updated or deleted record it owns. Soft-deleted records are reported as deleted,
+/// restored ones as updated.
+pub const LIVE_ENDPOINT: &str = "/api/live";
+
+/// The tables clients can subscribe to, by name.
+pub struct LiveTables(HashMap<&'static str, &'static QueryBuilder>);
+
+impl LiveTables {
+    /// Tables without ownership paths can't be filtered per user and are left out.
+    pub fn new(query_builders: &[&'static QueryBuilder]) -> Self {
+        Self(
+            query_builders
+                .iter()
+                .filter(|query_builder| !query_builder.paths.is_empty())
+                .map(|query_builder| (query_builder.table_name, *query_builder))
+                .collect(),
+        )
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum ClientMessage {
+    Subscribe { table: String },
+    Unsubscribe { table: String },
+}
+
+#[derive(Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum ServerMessage<'a> {
+    Subscribed {
+        table: &'a str,
+    },
+    Unsubscribed {
+        table: &'a str,
+    },
+    Notification {
+        table: &'a str,
+        action: LiveAction,
+        record: serde_json::Value,
+    },
+    Error {
+        message: String,
+    },
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "snake_case")]
+enum LiveAction {
+    Create,
+    Update,
+    Delete,
+}
+
+pub async fn live(
+    request: HttpRequest,
+    body: web::Payload,
+    user: UserDb,
+    tables: web::Data<LiveTables>,
+) -> Result<HttpResponse, actix_web::Error> {
+    let (response, mut session, mut messages) = actix_ws::handle(&request, body)?;
+    actix_web::rt::spawn(async move {
+        let mut subscriptions: HashMap<&'static str, JoinHandle<()>> = HashMap::new();
+        while let Some(Ok(message)) = messages.next().await {
+            let reply = match message {
+                Message::Text(text) => match serde_json::from_str::<ClientMessage>(&text) {
+                    Ok(ClientMessage::Subscribe { table }) => match tables.0.get(table.as_str()) {
+                        Some(query_builder) => {
+                            match subscribe(query_builder, &user, session.clone()).await {
+                                Ok(subscription) => {
+                                    if let Some(previous) =
+                                        subscriptions.insert(query_builder.table_name, subscription)
+                                    {
+                                        previous.abort();
+                                    }
+                                    ServerMessage::Subscribed { table: &table }.to_json()
+                                }
+                                Err(e) => ServerMessage::Error {
+                                    message: e.to_string(),
+                                }
+                                .to_json(),
+                            }
+                        }
+                        None => ServerMessage::Error {
+                            message: format!("Unknown table: {}", table),
+                        }
+                        .to_json(),
+                    },
+                    Ok(ClientMessage::Unsubscribe { table }) => {
+                        if let Some(subscription) = subscriptions.remove(table.as_str()) {
+                            subscription.abort();
+                        }
+                        ServerMessage::Unsubscribed { table: &table }.to_json()
+                    }
+                    Err(e) => ServerMessage::Error {
+                        message: e.to_string(),
+                    }
+                    .to_json(),
+                },
+                Message::Ping(bytes) => match session.pong(&bytes).await {
+                    Ok(_) => continue,
+                    Err(_) => break,
+                },
+                Message::Close(_) => break,
+                _ => continue,
+            };
+            if session.text(reply).await.is_err() {
+                break;
+            }
+        }
+        subscriptions.values().for_each(JoinHandle::abort);
+        let _ = session.close(None).await;
+    });
+    Ok(response)
+}
+
+/// Starts forwarding the changes of the records of the user to the session.
+/// Aborting the returned task drops the stream, which kills the live query.
+async fn subscribe(
+    query_builder: &'static QueryBuilder,
+    user: &UserDb,
+    mut session: Session,
+) -> Result<JoinHandle<()>, CrudError> {
+    let mut notifications = user
+        .db
+        .query(query_builder.prepared()?.live_select.as_str())
+        .await?
+        .stream::<surrealdb::Value>(0)?;
+    Ok(actix_web::rt::spawn(async move {
+        while let Some(notification) = notifications.next().await {
+            let record = notification.data.into_inner().into_json();
+            let action = match notification.action {
+                Action::Create => LiveAction::Create,
+                Action::Update
+                    if query_builder.options.soft_delete && !record[DELETED_AT_FIELD].is_null() =>
+                {
+                    LiveAction::Delete
+                }
+                Action::Update => LiveAction::Update,
+                Action::Delete => LiveAction::Delete,
+                _ => continue,
+            };
+            let message = ServerMessage::Notification {
+                table: query_builder.table_name,
+                action,
+                record,
+            };
+            if session.text(message.to_json()).await.is_err() {
+                break;
+            }
+        }
+    }))
+}
+
+impl ServerMessage<'_> {
+    fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+}
@@ -0,0 +1,638 @@
+use crate::live::LIVE_ENDPOINT;
+use crate::query_builder::{QueryBuilder, VERSION_FIELD};
+use crate::validation::PASSWORD_POLICIES_ENDPOINT;
+use crate::SessionConfig;
+use chrono::{DateTime, Utc};
+use serde_json::{json, Map, Value};
+use surrealdb::RecordId;
+
+/// Path the OpenAPI document is served at.
+pub const OPENAPI_ENDPOINT: &str = "/openapi.json";
+
+/// JSON Schema of a Rust type as it is serialized, used for the OpenAPI document.
+pub trait OpenApiType {
+    fn openapi_schema() -> Value;
+
+    /// Whether a struct field of this type must be present.
+    fn required() -> bool {
+        true
+    }
+}
+
+macro_rules! impl_openapi_type {
+    ($schema:tt: $($type:ty),*) => {
+        $(
+        impl OpenApiType for $type {
+            fn openapi_schema() -> Value {
+                json!($schema)
+            }
+        }
+        )*
+    };
+}
+
+impl_openapi_type!({ "type": "string" }: String, &str);
+impl_openapi_type!({ "type": "boolean" }: bool);
+impl_openapi_type!({ "type": "integer" }: i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+impl_openapi_type!({ "type": "number" }: f32, f64);
+impl_openapi_type!({ "type": "string", "format": "date-time" }: DateTime<Utc>, surrealdb::Datetime);
+impl_openapi_type!({ "$ref": "#/components/schemas/RecordId" }: RecordId);
+impl_openapi_type!({}: Value);
+
+impl<T: OpenApiType> OpenApiType for Option<T> {
+    fn openapi_schema() -> Value {
+        json!({ "anyOf": [T::openapi_schema(), { "type": "null" }] })
+    }
+
+    fn required() -> bool {
+        false
+    }
+}
+
+impl<T: OpenApiType> OpenApiType for Vec<T> {
+    fn openapi_schema() -> Value {
+        json!({ "type": "array", "items": T::openapi_schema() })
+    }
+}
+
+/// Reference to a schema of the `components` section.
+pub fn schema_ref(name: &str) -> Value {
+    json!({ "$ref": format!("#/components/schemas/{}", name) })
+}
+
+/// A field of an entity deriving `ApiEntity`.
+pub struct FieldDoc {
+    pub name: &'static str,
+    /// The Rust type as declared, used by the generated clients.
+    pub rust_type: &'static str,
+    pub schema: Value,
+    pub required: bool,
+    /// The validators and markers as declared, e.g. `v1_gt_v2(end_date)`.
+    pub validators: &'static [&'static str],
+    /// The name of the errors of the entity held by a `nested` field, which replace the list of
+    /// errors of the field.
+    pub nested_error: Option<&'static str>,
+}
+
+/// An entity deriving `ApiEntity`, documented as the `name` and `error_name` schemas
+/// and, unless it has no table or no ownership paths, the routes of its table.
+pub struct EntityDoc {
+    pub name: &'static str,
+    pub error_name: &'static str,
+    pub query_builder: &'static QueryBuilder,
+    pub fields: Vec<FieldDoc>,
+    /// The validators of the whole entity as declared.
+    pub validators: &'static [&'static str],
+}
+
+/// A page rendering the document with Swagger UI, off unless set in [`OpenApiConfig`].
+///
+/// The page loads the files of the `swagger-ui-dist` package, bundled with the server in
+/// `assets_dir`, e.g. with `npm pack swagger-ui-dist@5` extracted there, and served at
+/// `assets_path` by the static files handler.
+pub struct SwaggerUi {
+    pub path: &'static str,
+    pub assets_path: &'static str,
+    /// Relative to the working directory of the server.
+    pub assets_dir: &'static str,
+}
+
+impl Default for SwaggerUi {
+    fn default() -> Self {
+        Self {
+            path: "/swagger",
+            assets_path: "/swagger-ui",
+            assets_dir: "swagger-ui",
+        }
+    }
+}
+
+impl SwaggerUi {
+    pub fn page(&self) -> String {
+        format!(
+            r##"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<title>API documentation</title>
+<link rel="stylesheet" href="{0}/swagger-ui.css">
+</head>
+<body>
+<div id="swagger-ui"></div>
+<script src="{0}/swagger-ui-bundle.js"></script>
+<script>SwaggerUIBundle({{ url: "{1}", dom_id: "#swagger-ui" }});</script>
+</body>
+</html>"##,
+            self.assets_path.trim_end_matches('/'),
+            OPENAPI_ENDPOINT
+        )
+    }
+}
+
+/// The document served at [`OPENAPI_ENDPOINT`] by `ServerStarter::start`.
+pub struct OpenApiConfig {
+    pub title: &'static str,
+    pub version: &'static str,
+    /// See `openapi()` of `#[derive(ApiEntity)]`.
+    pub entities: Vec<EntityDoc>,
+    pub swagger_ui: Option<SwaggerUi>,
+}
+
+impl Default for OpenApiConfig {
+    fn default() -> Self {
+        Self {
+            title: "API",
+            version: "0.1.0",
+            entities: Vec::new(),
+            swagger_ui: None,
+        }
+    }
+}
+
+impl OpenApiConfig {
+    /// Builds the document. `TCreds`, `TRegisterData` and `TRegisterDataError` are the types
+    /// accepted and returned by the authentication endpoints.
+    pub fn document<TCreds, TRegisterData, TRegisterDataError>(
+        &self,
+        session_config: &SessionConfig,
+    ) -> Value
+    where
+        TCreds: OpenApiType,
+        TRegisterData: OpenApiType,
+        TRegisterDataError: OpenApiType,
+    {
+        let mut schemas = built_in_schemas();
+        let mut paths = authentication_paths(
+            TCreds::openapi_schema(),
+            TRegisterData::openapi_schema(),
+            TRegisterDataError::openapi_schema(),
+        );
+        for entity in &self.entities {
+            schemas.insert(entity.name.to_string(), entity.schema());
+            schemas.insert(entity.error_name.to_string(), entity.error_schema());
+            if !entity.query_builder.options.no_table && !entity.query_builder.paths.is_empty() {
+                paths.extend(entity.paths());
+            }
+        }
+        paths.insert(
+            "/api/search".to_string(),
+            json!({ "get": operation(
+                "Full-text search through every searchable table, by table name",
+                vec![query_parameter("q")],
+                None,
+                json_response("Matches by table name", json!({
+                    "type": "object",
+                    "additionalProperties": { "type": "array", "items": {} },
+                })),
+            ) }),
+        );
+        paths.insert(
+            LIVE_ENDPOINT.to_string(),
+            json!({ "get": operation(
+                "WebSocket streaming the changes of the records of the user",
+                Vec::new(),
+                None,
+                json!({ "101": { "description": "Switching to the WebSocket protocol" } }),
+            ) }),
+        );
+        json!({
+            "openapi": "3.1.0",
+            "info": { "title": self.title, "version": self.version },
+            "paths": paths,
+            "components": {
+                "schemas": schemas,
+                "securitySchemes": {
+                    "accessToken": {
+                        "type": "apiKey",
+                        "in": "cookie",
+                        "name": session_config.access_token_cookie_name,
+                    },
+                },
+            },
+            "security": [{ "accessToken": [] }],
+        })
+    }
+}
+
+impl EntityDoc {
+    fn schema(&self) -> Value {
+        let mut properties = Map::new();
+        let mut required = Vec::new();
+        for field in &self.fields {
+            // Raw identifiers like `r#type` are serialized without their prefix.
+            let name = field.name.trim_start_matches("r#");
+            // Owner fields are set by the server and may be left out of the requests.
+            if self
+                .query_builder
+                .options
+                .owner_fields()
+                .any(|owner| owner == field.name)
+            {
+                let mut schema = field.schema.clone();
+                schema["description"] = json!("The user of the session, set by the server");
+                properties.insert(name.to_string(), schema);
+                continue;
+            }
+            if self
+                .query_builder
+                .options
+                .server_filled()
+                .any(|server_field| server_field == field.name)
+            {
+                let mut schema = field.schema.clone();
+                schema["description"] = json!("Set by the server");
+                properties.insert(name.to_string(), schema);
+                continue;
+            }
+            properties.insert(name.to_string(), field.schema.clone());
+            if field.required {
+                required.push(name);
+            }
+        }
+        if self.query_builder.options.versioned {
+            properties.insert(VERSION_FIELD.to_string(), json!({ "type": "integer" }));
+        }
+        json!({ "type": "object", "properties": properties, "required": required })
+    }
+
+    /// Every field gets the list of the validation errors it failed with, or the errors of its
+    /// entity for `nested` fields, and `_entity` the ones of the validators of the whole entity.
+    fn error_schema(&self) -> Value {
+        let errors = json!({ "type": "array", "items": schema_ref("ValidationError") });
+        let mut names = Vec::new();
+        let mut properties = Map::new();
+        for field in &self.fields {
+            let name = field.name.trim_start_matches("r#");
+            let schema = match field.nested_error {
+                Some(nested) => json!({ "anyOf": [schema_ref(nested), { "type": "null" }] }),
+                None => errors.clone(),
+            };
+            names.push(name);
+            properties.insert(name.to_string(), schema);
+        }
+        names.push("_entity");
+        properties.insert("_entity".to_string(), errors);
+        json!({ "type": "object", "properties": properties, "required": names })
+    }
+
+    fn paths(&self) -> Map<String, Value> {
+        let options = &self.query_builder.options;
+        let base = format!("/api/{}", self.query_builder.table_name);
+        let entity = schema_ref(self.name);
+        let id = schema_ref("RecordId");
+        let ids = json!({ "type": "array", "items": id });
+        let mut paths = Map::new();
+        paths.insert(
+            format!("{}/all", base),
+            json!({ "get": operation(
+                "Lists the records of the user",
+                Vec::new(),
+                None,
+                json_response("The records", json!({ "type": "array", "items": entity })),
+            ) }),
+        );
+        let mut insert_responses = json_response("Id of the inserted record", id.clone());
+        let mut update_responses = empty_response("Updated");
+        if !self.error_name.is_empty() {
+            let invalid_response = json!({
+                "description": "The validation errors of the content",
+                "content": { "application/json": { "schema": schema_ref(self.error_name) } },
+            });
+            insert_responses["422"] = invalid_response.clone();
+            update_responses["422"] = invalid_response;
+        }
+        if options.versioned {
+            update_responses["409"] = json!({
+                "description": "The record changed since the stated version, its current state",
+                "content": { "application/json": { "schema": entity } },
+            });
+            update_responses["428"] = json!({ "description": "No version was stated" });
+        }
+        let mut delete_responses = empty_response("Deleted");
+        delete_responses["409"] = json!({
+            "description": "Records reference it through a restricting reference",
+            "content": { "text/plain": { "schema": { "type": "string" } } },
+        });
+        paths.insert(
+            base.clone(),
+            json!({
+                "get": operation("Selects a record by id", Vec::new(), Some(id.clone()), json_response("The record", entity.clone())),
+                "post": operation("Inserts a record", Vec::new(), Some(entity.clone()), insert_responses),
+                "put": operation(
+                    "Replaces the content of a record",
+                    match options.versioned {
+                        true => vec![json!({
+                            "name": "If-Match",
+                            "in": "header",
+                            "description": "Version the content is based on, instead of its `version` field",
+                            "schema": { "type": "string" },
+                        })],
+                        false => Vec::new(),
+                    },
+                    Some(with_id(entity.clone())),
+                    update_responses,
+                ),
+                "delete": operation("Deletes a record by id", Vec::new(), Some(id.clone()), delete_responses),
+            }),
+        );
+        paths.insert(
+            format!("{}/batch", base),
+            json!({
+                "post": operation(
+                    "Inserts all of the records or none of them",
+                    Vec::new(),
+                    Some(json!({ "type": "array", "items": entity })),
+                    json_response("Per-item results", batch_result(id.clone(), self.error_name)),
+                ),
+                "put": operation(
+                    "Replaces the content of all of the records or of none of them",
+                    Vec::new(),
+                    Some(json!({ "type": "array", "items": with_id(entity.clone()) })),
+                    json_response("Per-item results", batch_result(json!({ "type": "null" }), self.error_name)),
+                ),
+                "delete": operation(
+                    "Deletes all of the records or none of them",
+                    Vec::new(),
+                    Some(ids),
+                    json_response("Per-item results", batch_result(json!({ "type": "null" }), "")),
+                ),
+            }),
+        );
+        if options.soft_delete {
+            paths.insert(
+                format!("{}/restore", base),
+                json!({ "post": operation("Restores a soft-deleted record", Vec::new(), Some(id.clone()), empty_response("Restored")) }),
+            );
+            paths.insert(
+                format!("{}/trash", base),
+                json!({ "get": operation(
+                    "Lists the soft-deleted records of the user",
+                    Vec::new(),
+                    None,
+                    json_response("The records", json!({ "type": "array", "items": entity })),
+                ) }),
+            );
+        }
+        if options.audit {
+            paths.insert(
+                format!("{}/history", base),
+                json!({ "get": operation(
+                    "Lists the changes of a record, oldest first",
+                    vec![query_parameter("id")],
+                    None,
+                    json_response("The changes", json!({ "type": "array", "items": schema_ref("HistoryEntry") })),
+                ) }),
+            );
+        }
+        if !options.searchable.is_empty() {
+            paths.insert(
+                format!("{}/search", base),
+                json!({ "get": operation(
+                    "Full-text search through the searchable fields, best matches first",
+                    vec![query_parameter("q")],
+                    None,
+                    json_response("The matching records with their `score`", json!({ "type": "array", "items": entity })),
+                ) }),
+            );
+        }
+        if !options.aggregations.is_empty() {
+            paths.insert(
+                format!("{}/aggregate/{{name}}", base),
+                json!({ "get": operation(
+                    "Runs a declared aggregation over the records of the user",
+                    vec![json!({
+                        "name": "name",
+                        "in": "path",
+                        "required": true,
+                        "schema": {
+                            "type": "string",
+                            "enum": options.aggregations.iter().map(|aggregation| aggregation.name).collect::<Vec<_>>(),
+                        },
+                    })],
+                    None,
+                    json_response("One object per group, with its keys and `value`", json!({ "type": "array", "items": { "type": "object" } })),
+                ) }),
+            );
+        }
+        paths
+    }
+}
+
+fn operation(
+    summary: &str,
+    parameters: Vec<Value>,
+    body: Option<Value>,
+    responses: Value,
+) -> Value {
+    let mut operation = json!({ "summary": summary, "responses": responses });
+    if !parameters.is_empty() {
+        operation["parameters"] = Value::Array(parameters);
+    }
+    if let Some(body) = body {
+        operation["requestBody"] = json!({
+            "required": true,
+            "content": { "application/json": { "schema": body } },
+        });
+    }
+    if operation["responses"].get("500").is_none() {
+        operation["responses"]["500"] = json!({ "description": "Server error" });
+    }
+    operation
+}
+
+fn query_parameter(name: &str) -> Value {
+    json!({ "name": name, "in": "query", "required": true, "schema": { "type": "string" } })
+}
+
+fn json_response(description: &str, schema: Value) -> Value {
+    json!({ "200": { "description": description, "content": { "application/json": { "schema": schema } } } })
+}
+
+fn empty_response(description: &str) -> Value {
+    json!({ "200": { "description": description } })
+}
+
+fn with_id(data: Value) -> Value {
+    json!({
+        "type": "object",
+        "properties": { "id": schema_ref("RecordId"), "data": data },
+        "required": ["id", "data"],
+    })
+}
+
+/// `BatchResult` items serialize as `{"Ok": ...}` or `{"Err": ...}`.
+fn batch_result(ok: Value, error_name: &str) -> Value {
+    let mut errors = vec![json!({ "type": "string", "enum": ["Unauthorized", "RolledBack"] })];
+    if !error_name.is_empty() {
+        errors.push(json!({
+            "type": "object",
+            "properties": { "Invalid": schema_ref(error_name) },
+            "required": ["Invalid"],
+        }));
+    }
+    json!({
+        "type": "array",
+        "items": { "oneOf": [
+            { "type": "object", "properties": { "Ok": ok }, "required": ["Ok"] },
+            { "type": "object", "properties": { "Err": { "oneOf": errors } }, "required": ["Err"] },
+        ] },
+    })
+}
+
+/// Schemas of the types of the starter, including the envelopes of `actix_surreal_types::Error`.
+fn built_in_schemas() -> Map<String, Value> {
+    let mut schemas = Map::new();
+    schemas.insert(
+        "RecordId".to_string(),
+        json!({
+            "type": "object",
+            "properties": {
+                "tb": { "type": "string" },
+                "id": {
+                    "type": "object",
+                    "description": "The key by kind, e.g. `{\"String\": \"abc\"}` or `{\"Number\": 1}`",
+                },
+            },
+            "required": ["tb", "id"],
+        }),
+    );
+    schemas.insert(
+        "ValidationError".to_string(),
+        json!({
+            "description": "A validation error, `params` holding the values of the placeholders of its message",
+            "type": "object",
+            "properties": {
+                "code": { "type": "string" },
+                "params": { "type": "object" },
+            },
+            "required": ["code"],
+        }),
+    );
+    schemas.insert(
+        "HistoryEntry".to_string(),
+        json!({
+            "type": "object",
+            "properties": {
+                "record": schema_ref("RecordId"),
+                "action": { "type": "string", "enum": ["insert", "update", "delete", "restore"] },
+                "before": { "type": ["object", "null"] },
+                "after": { "type": ["object", "null"] },
+                "user_id": schema_ref("RecordId"),
+                "timestamp": { "type": "string", "format": "date-time" },
+            },
+        }),
+    );
+    schemas.insert(
+        "ClientError".to_string(),
+        json!({
+            "description": "Responded with status 200",
+            "type": "object",
+            "properties": { "Err": {
+                "type": "string",
+                "enum": ["NoAccessToken", "NoRefreshToken", "InvalidCredentials", "EmailTaken", "InvalidAccessToken"],
+            } },
+            "required": ["Err"],
+        }),
+    );
+    schemas.insert(
+        "ServerError".to_string(),
+        json!({
+            "description": "Responded with status 500",
+            "type": "object",
+            "properties": {
+                "Db": { "type": "string" },
+                "PasswordHashing": { "type": "string" },
+            },
+            "minProperties": 1,
+            "maxProperties": 1,
+        }),
+    );
+    schemas
+}
+
+/// Successful authentication sets the session cookies, failures respond with a `ClientError`.
+fn authentication_paths(
+    creds: Value,
+    register_data: Value,
+    register_error: Value,
+) -> Map<String, Value> {
+    let session_responses = |description: &str| {
+        json!({
+            "200": {
+                "description": format!("{}, or a `ClientError`", description),
+                "content": { "application/json": { "schema": schema_ref("ClientError") } },
+            },
+            "500": {
+                "description": "Server error",
+                "content": { "application/json": { "schema": schema_ref("ServerError") } },
+            },
+        })
+    };
+    let public = |mut operation: Value| {
+        operation["security"] = json!([]);
+        operation
+    };
+    let mut paths = Map::new();
+    paths.insert(
+        "/login".to_string(),
+        json!({ "post": public(operation("Starts a session", Vec::new(), Some(creds), session_responses("Session cookies set"))) }),
+    );
+    let mut register_responses = session_responses("Session cookies set");
+    register_responses["200"]["content"]["application/json"]["schema"] = json!({ "oneOf": [
+        schema_ref("ClientError"),
+        { "type": "object", "properties": { "Err": register_error }, "required": ["Err"] },
+    ] });
+    paths.insert(
+        "/register".to_string(),
+        json!({ "post": public(operation(
+            "Creates a user and starts a session. Invalid data responds with its validation errors",
+            Vec::new(),
+            Some(register_data.clone()),
+            register_responses,
+        )) }),
+    );
+    paths.insert(
+        "/logout".to_string(),
+        json!({ "post": operation("Ends the session", Vec::new(), None, session_responses("Session cookies removed")) }),
+    );
+    paths.insert(
+        "/refresh".to_string(),
+        json!({ "post": public(operation(
+            "Renews the session with the refresh token cookie",
+            Vec::new(),
+            None,
+            session_responses("Session cookies renewed"),
+        )) }),
+    );
+    let mut me_responses = session_responses("The user");
+    me_responses["200"]["content"]["application/json"]["schema"] =
+        json!({ "oneOf": [register_data, schema_ref("ClientError")] });
+    paths.insert(
+        PASSWORD_POLICIES_ENDPOINT.to_string(),
+        json!({ "get": public(operation(
+            "The password policies of the `password` validators, by name",
+            Vec::new(),
+            None,
+            json_response("Policies by name", json!({ "type": "object", "additionalProperties": {
+                "type": "object",
+                "properties": {
+                    "min_length": { "type": "integer" },
+                    "max_length": { "type": "integer" },
+                    "require_uppercase": { "type": "boolean" },
+                    "require_lowercase": { "type": "boolean" },
+                    "require_digit": { "type": "boolean" },
+                    "require_special": { "type": "boolean" },
+                    "allow_unicode": { "type": "boolean" },
+                    "allow_spaces": { "type": "boolean" },
+                    "max_similarity": { "type": ["number", "null"] },
+                    "reject_breached": { "type": "boolean" },
+                },
+            } })),
+        )) }),
+    );
+    paths.insert(
+        "/me".to_string(),
+        json!({ "get": operation("Data of the user of the session, without the password", Vec::new(), None, me_responses) }),
+    );
+    paths
+}
@@ -1,2 +1,3 @@
-pub mod validators;
-mod regexes;
\ No newline at end of file
+/// Kept here for the entities declared before the validators moved to `actix-surreal-types`,
+/// which compiles to WASM for the clients.
+pub use actix_surreal_types::validators;
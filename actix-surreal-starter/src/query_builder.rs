@@ -1,4 +1,6 @@
+use std::collections::HashMap;
 use std::fmt;
+use std::sync::OnceLock;
 use thiserror::Error;
 
 pub type BuilderResult = Result<String, BuilderError>;
@@ -11,6 +13,12 @@ pub enum BuilderError {
     #[error("Foreign key map is not defined in the builder, but you are trying to select items by a foreign key. Consider providing fkey_map with all the necessary `fkey => validation path` associations"
     )]
     FkeyMap(String),
+    #[error(
+        "Table `{0}` has no ownership paths, so its records can't be checked against `$user_id`"
+    )]
+    NoOwnershipPaths(&'static str),
+    #[error("Query `{0}` for table `{1}` was rejected by the SurrealQL parser: {2}\nQuery: {3}")]
+    Parse(&'static str, &'static str, String, String),
 }
 
 #[derive(Debug, Error)]
@@ -27,15 +35,359 @@ pub struct QueryBuilder {
     pub table_name: &'static str,
     pub paths: &'static [&'static str],
     pub fkey_path_map: Option<&'static phf::Map<&'static str, &'static str>>,
+    pub options: EntityOptions,
+    prepared: OnceLock<PreparedQueries>,
+}
+
+/// Field holding the deletion time of soft-deleted records.
+pub const DELETED_AT_FIELD: &str = "deleted_at";
+
+/// Suffix of the tables receiving the change history of audited tables.
+pub const HISTORY_TABLE_SUFFIX: &str = "_history";
+
+/// Field holding the version of the records of versioned tables.
+pub const VERSION_FIELD: &str = "version";
+
+/// Error thrown when an update of a versioned table is based on a stale version.
+pub const CONFLICT_ERR: &str = "CONFLICT";
+
+/// Analyzer of the full-text indexes on searchable fields.
+pub const SEARCH_ANALYZER: &str = "api_search";
+
+/// Maximum number of records returned by a search.
+pub const SEARCH_LIMIT: u32 = 50;
+
+/// Prefix of the error thrown when a delete is blocked by an [`OnDeleteAction::Restrict`] reference.
+pub const RESTRICTED_ERR: &str = "RESTRICTED";
+
+/// What happens to the records of `table` referencing a deleted record through `field`.
+#[derive(Debug, Clone, Copy)]
+pub enum OnDeleteAction {
+    /// The referencing records are deleted as well.
+    Cascade,
+    /// The delete fails with [`RESTRICTED_ERR`] while any referencing record exists.
+    Restrict,
+    /// The reference is cleared. The field must be optional.
+    SetNone,
+}
+
+/// A reference to the table of a `QueryBuilder`, with the rule applied when its records are deleted.
+#[derive(Debug, Clone, Copy)]
+pub struct OnDelete {
+    pub table: &'static str,
+    pub field: &'static str,
+    pub action: OnDeleteAction,
+}
+
+impl OnDelete {
+    pub const fn cascade(table: &'static str, field: &'static str) -> Self {
+        Self {
+            table,
+            field,
+            action: OnDeleteAction::Cascade,
+        }
+    }
+
+    pub const fn restrict(table: &'static str, field: &'static str) -> Self {
+        Self {
+            table,
+            field,
+            action: OnDeleteAction::Restrict,
+        }
+    }
+
+    pub const fn set_none(table: &'static str, field: &'static str) -> Self {
+        Self {
+            table,
+            field,
+            action: OnDeleteAction::SetNone,
+        }
+    }
+}
+
+/// Function an [`Aggregation`] applies to the records of each group.
+#[derive(Debug, Clone, Copy)]
+pub enum AggregateFunction {
+    Sum,
+    Count,
+    Avg,
+}
+
+/// Granularity of a [`GroupBy::Date`] bucket.
+#[derive(Debug, Clone, Copy)]
+pub enum DateBucket {
+    Day,
+    Month,
+    Year,
 }
+
+/// What the records of an [`Aggregation`] are grouped by. The key of every group is returned
+/// under the name of the field.
+#[derive(Debug, Clone, Copy)]
+pub enum GroupBy {
+    Field(&'static str),
+    /// The datetime field, truncated to the start of its bucket.
+    Date(&'static str, DateBucket),
+}
+
+/// A named summary of the records of the user, exposed under `/api/<table>/aggregate/<name>`.
+/// Every group is returned with its keys and the aggregated `value`.
+#[derive(Debug, Clone, Copy)]
+pub struct Aggregation {
+    pub name: &'static str,
+    pub function: AggregateFunction,
+    /// Numeric field to aggregate, ignored by [`AggregateFunction::Count`].
+    pub field: &'static str,
+    /// No groups aggregate all the records together.
+    pub group_by: &'static [GroupBy],
+}
+
+impl Aggregation {
+    pub const fn sum(
+        name: &'static str,
+        field: &'static str,
+        group_by: &'static [GroupBy],
+    ) -> Self {
+        Self {
+            name,
+            function: AggregateFunction::Sum,
+            field,
+            group_by,
+        }
+    }
+
+    pub const fn count(name: &'static str, group_by: &'static [GroupBy]) -> Self {
+        Self {
+            name,
+            function: AggregateFunction::Count,
+            field: "",
+            group_by,
+        }
+    }
+
+    pub const fn avg(
+        name: &'static str,
+        field: &'static str,
+        group_by: &'static [GroupBy],
+    ) -> Self {
+        Self {
+            name,
+            function: AggregateFunction::Avg,
+            field,
+            group_by,
+        }
+    }
+}
+
+/// How the server fills a [`ServerField`]. Values sent by the clients are discarded.
+#[derive(Debug, Clone, Copy)]
+pub enum ServerValue {
+    /// `[readonly]`: empty on insert and kept as stored on update, so non-optional fields
+    /// need a default instead.
+    Readonly,
+    /// `[server_default(now)]` or `[server_default(<literal>)]`: the SurrealQL value on insert,
+    /// kept as stored on update.
+    Default(&'static str),
+    /// `[computed("<SurrealQL>")]`: the expression over the other fields of the written content,
+    /// evaluated on every insert and update.
+    Computed(&'static str),
+    /// `[owner]`: the user of the session on insert, kept as stored on update. Ownership paths
+    /// made of the field alone always pass for the written content.
+    Owner,
+}
+
+/// A field marked in `#[derive(ApiEntity)]` as not writable by the clients.
+#[derive(Debug, Clone, Copy)]
+pub struct ServerField {
+    pub name: &'static str,
+    pub value: ServerValue,
+}
+
+/// Per-table switches, declared after the ownership paths in `#[api(...)]`.
+#[derive(Debug, Clone, Copy)]
+pub struct EntityOptions {
+    /// Deleting a record sets its `deleted_at` instead of removing it, and soft-deleted
+    /// records are excluded from every query except `restore` and `select_deleted`.
+    pub soft_delete: bool,
+    /// SurrealQL duration after which soft-deleted records are purged for good.
+    pub purge_after: &'static str,
+    /// Rules for the records referencing a deleted record. Only the direct references are
+    /// handled. With `soft_delete`, restrictions are checked on delete, while cascades and
+    /// cleared references are applied once the record is purged.
+    pub on_delete: &'static [OnDelete],
+    /// Every mutation made through the API writes a row into `<table>_history` with the state
+    /// of the record before and after it, the acting user and the time.
+    pub audit: bool,
+    /// Records carry a [`VERSION_FIELD`] starting at 1 and incremented by every update.
+    /// Updates must state the version they are based on and fail with [`CONFLICT_ERR`]
+    /// if the record has changed since.
+    pub versioned: bool,
+    pub aggregations: &'static [Aggregation],
+    /// Fields marked `[searchable]`, indexed for full-text search at startup.
+    pub searchable: &'static [&'static str],
+    /// Fields marked `[readonly]`, `[server_default(...)]` or `[computed(...)]`.
+    pub server_fields: &'static [ServerField],
+    /// The entity only describes request data, like a login form, and has no table.
+    pub no_table: bool,
+}
+
+impl EntityOptions {
+    pub const DEFAULT: Self = Self {
+        soft_delete: false,
+        purge_after: "30d",
+        on_delete: &[],
+        audit: false,
+        versioned: false,
+        aggregations: &[],
+        searchable: &[],
+        server_fields: &[],
+        no_table: false,
+    };
+
+    /// Names of the fields the server fills in, which the clients may leave out.
+    pub fn server_filled(&self) -> impl Iterator<Item = &'static str> {
+        self.server_fields.iter().map(|field| field.name)
+    }
+
+    /// Names of the fields marked `[owner]`.
+    pub fn owner_fields(&self) -> impl Iterator<Item = &'static str> {
+        self.server_fields
+            .iter()
+            .filter(|field| matches!(field.value, ServerValue::Owner))
+            .map(|field| field.name)
+    }
+}
+
+/// CRUD queries of a single table, built and validated once and reused for every request.
+#[derive(Debug)]
+pub struct PreparedQueries {
+    pub insert: String,
+    pub select: String,
+    pub select_all: String,
+    pub update: String,
+    pub delete: String,
+    pub insert_batch: String,
+    pub update_batch: String,
+    pub delete_batch: String,
+    pub restore: String,
+    pub select_deleted: String,
+    pub purge_deleted: String,
+    pub select_history: String,
+    pub live_select: String,
+    pub aggregations: HashMap<&'static str, String>,
+    /// `None` unless the table has searchable fields.
+    pub define_search: Option<String>,
+    pub search: Option<String>,
+}
+
 impl QueryBuilder {
+    pub const fn new(
+        table_name: &'static str,
+        paths: &'static [&'static str],
+        fkey_path_map: Option<&'static phf::Map<&'static str, &'static str>>,
+        options: EntityOptions,
+    ) -> Self {
+        Self {
+            table_name,
+            paths,
+            fkey_path_map,
+            options,
+            prepared: OnceLock::new(),
+        }
+    }
+
+    /// Returns the cached CRUD queries, building and validating them on the first call.
+    ///
+    /// Call it for every builder during startup so that malformed queries fail the boot
+    /// instead of the first request.
+    pub fn prepared(&self) -> Result<&PreparedQueries, BuilderError> {
+        if let Some(prepared) = self.prepared.get() {
+            return Ok(prepared);
+        }
+        let prepared = PreparedQueries {
+            insert: self.validated("insert", self.insert()?)?,
+            select: self.validated("select", self.select()?)?,
+            select_all: self.validated("select_all", self.select_all()?)?,
+            update: self.validated("update", self.update()?)?,
+            delete: self.validated("delete", self.delete()?)?,
+            insert_batch: self.validated("insert_batch", self.insert_batch()?)?,
+            update_batch: self.validated("update_batch", self.update_batch()?)?,
+            delete_batch: self.validated("delete_batch", self.delete_batch()?)?,
+            restore: self.validated("restore", self.restore()?)?,
+            select_deleted: self.validated("select_deleted", self.select_deleted()?)?,
+            purge_deleted: self.validated("purge_deleted", self.purge_deleted()?)?,
+            select_history: self.validated("select_history", self.select_history()?)?,
+            live_select: self.validated("live_select", self.live_select()?)?,
+            aggregations: self
+                .options
+                .aggregations
+                .iter()
+                .map(|aggregation| {
+                    Ok((
+                        aggregation.name,
+                        self.validated(aggregation.name, self.aggregate(aggregation)?)?,
+                    ))
+                })
+                .collect::<Result<_, BuilderError>>()?,
+            define_search: match self.options.searchable.is_empty() {
+                true => None,
+                false => Some(self.validated("define_search", self.define_search())?),
+            },
+            search: match self.options.searchable.is_empty() {
+                true => None,
+                false => Some(self.validated("search", self.search()?)?),
+            },
+        };
+        Ok(self.prepared.get_or_init(|| prepared))
+    }
+
+    /// The first ownership path, leading from a record of the table directly to its owner.
+    fn root_path(&self) -> Result<&'static [&'static str], BuilderError> {
+        match self.paths.is_empty() {
+            true => Err(BuilderError::NoOwnershipPaths(self.table_name)),
+            false => Ok(&self.paths[..1]),
+        }
+    }
+
+    /// The ownership paths to check on content sent by a user, without the `[owner]` fields
+    /// that are set by the server.
+    fn content_paths(&self) -> Vec<&'static str> {
+        self.paths
+            .iter()
+            .copied()
+            .filter(|path| !self.options.owner_fields().any(|field| field == *path))
+            .collect()
+    }
+
+    /// Condition to append to a `WHERE` clause to skip soft-deleted records.
+    fn not_deleted(&self, prefix: &str) -> String {
+        match self.options.soft_delete {
+            true => format!(" {} {} IS NONE", prefix, DELETED_AT_FIELD),
+            false => String::new(),
+        }
+    }
+
+    fn validated(&self, query_name: &'static str, query: String) -> BuilderResult {
+        match surrealdb::sql::parse(&query) {
+            Ok(_) => Ok(query),
+            Err(e) => Err(BuilderError::Parse(
+                query_name,
+                self.table_name,
+                e.to_string(),
+                query,
+            )),
+        }
+    }
+
     /// To bind:
     /// - $value
     pub fn insert(&self) -> BuilderResult {
         wrap_in_transaction(build_validation_segment(
             "$value",
-            self.paths,
-            format!("INSERT INTO {} $value RETURN ID;", self.table_name),
+            &self.content_paths(),
+            self.build_audited_insert(
+                &self.with_version(&self.with_server_fields("$value", None), "1"),
+            ),
         ))
     }
 
@@ -44,8 +396,8 @@ impl QueryBuilder {
     pub fn select(&self) -> BuilderResult {
         wrap_in_transaction(build_validation_segment(
             "$id",
-            &self.paths[..1],
-            "SELECT * FROM $id".to_string(),
+            self.root_path()?,
+            format!("(SELECT * FROM $id{})", self.not_deleted("WHERE")),
         ))
     }
 
@@ -53,8 +405,10 @@ impl QueryBuilder {
     ///
     pub fn select_all(&self) -> BuilderResult {
         wrap_in_transaction(format!(
-            "SELECT * FROM {} WHERE {} = $user_id",
-            self.table_name, self.paths[0]
+            "SELECT * FROM {} WHERE {} = $user_id{}",
+            self.table_name,
+            self.root_path()?[0],
+            self.not_deleted("AND"),
         ))
     }
 
@@ -70,8 +424,10 @@ impl QueryBuilder {
                     .ok_or(BuilderError::Fkey(fkey.to_string()))?,
             ),
             format!(
-                "SELECT * FROM {} WHERE {} = $fkey",
-                self.table_name, self.paths[0]
+                "SELECT * FROM {} WHERE {} = $fkey{}",
+                self.table_name,
+                self.root_path()?[0],
+                self.not_deleted("AND"),
             ),
         ))
     }
@@ -81,25 +437,505 @@ impl QueryBuilder {
     pub fn delete(&self) -> BuilderResult {
         wrap_in_transaction(build_validation_segment(
             "$id",
-            &self.paths[..1],
-            "DELETE $id".to_string(),
+            self.root_path()?,
+            match self.options.soft_delete {
+                true => format!(
+                    "{}{}",
+                    self.build_restrictions("[$id]"),
+                    self.build_audited(
+                        "delete",
+                        "[$id]",
+                        format!(
+                            "UPDATE $id SET {0} = time::now() WHERE {0} IS NONE",
+                            DELETED_AT_FIELD
+                        ),
+                    ),
+                ),
+                false => format!(
+                    "{}{}{}",
+                    self.build_restrictions("[$id]"),
+                    self.build_reference_updates("[$id]"),
+                    self.build_audited("delete", "[$id]", "DELETE $id".to_string()),
+                ),
+            },
         ))
     }
 
     /// To bind:
     /// - $id
     /// - $value
+    /// - $version, if the table is versioned
     pub fn update(&self) -> BuilderResult {
         wrap_in_transaction(build_validation_segment(
             "$id",
-            &self.paths[..1],
+            self.root_path()?,
+            format!(
+                "{}{};{};",
+                self.build_version_check("$id", "$version"),
+                self.build_audited(
+                    "update",
+                    "[$id]",
+                    format!(
+                        "UPDATE $id CONTENT {}{}",
+                        self.with_version(
+                            &self.with_server_fields("$value", Some("$id")),
+                            "$version + 1"
+                        ),
+                        self.not_deleted("WHERE"),
+                    ),
+                ),
+                build_validation_segment("$id", self.paths, "RETURN NONE;".to_string()),
+            ),
+        ))
+    }
+
+    /// Inserts all `$values` or none of them.
+    ///
+    /// Returns `{ owned, ids }`, where `owned` tells for every item whether it passes
+    /// all ownership paths. Nothing is inserted and `ids` is empty unless all of them do.
+    ///
+    /// To bind:
+    /// - $values
+    pub fn insert_batch(&self) -> BuilderResult {
+        wrap_in_transaction(build_batch_segment(
+            build_ownership_mask("$values", &self.content_paths()),
+            self.build_audited_insert(&match self.options.versioned
+                || !self.options.server_fields.is_empty()
+            {
+                // Closures don't see `$user_id`, unlike the projections of a `SELECT`.
+                true => format!(
+                    "(SELECT VALUE {} FROM $values)",
+                    self.with_version(&self.with_server_fields("$this", None), "1")
+                ),
+                false => "$values".to_string(),
+            }),
+        ))
+    }
+
+    /// Replaces the content of every `$values[n].id` with `$values[n].data`, all or nothing.
+    /// Both the current record and the new content must pass the ownership paths.
+    /// For versioned tables, `$values[n].data.version` must match the current version.
+    ///
+    /// To bind:
+    /// - $values
+    pub fn update_batch(&self) -> BuilderResult {
+        wrap_in_transaction(build_batch_segment(
+            format!(
+                "array::boolean_and({}, {})",
+                build_ownership_mask("$values.id", self.root_path()?),
+                build_ownership_mask("$values.data", &self.content_paths()),
+            ),
             format!(
-                "{};{};",
-                "UPDATE $id CONTENT $value;",
-                build_validation_segment("id", &self.paths, "RETURN NONE;".to_string()),
+                "{}; []",
+                self.build_audited(
+                    "update",
+                    "$values.id",
+                    format!(
+                        "FOR $value IN $values {{ {}UPDATE $value.id CONTENT {}{}; }}",
+                        self.build_version_check("$value.id", "$value.data.version"),
+                        self.with_version(
+                            &self.with_server_fields("$value.data", Some("$value.id")),
+                            "$value.data.version + 1"
+                        ),
+                        self.not_deleted("WHERE"),
+                    ),
+                ),
+            ),
+        ))
+    }
+
+    /// To bind:
+    /// - $ids
+    pub fn delete_batch(&self) -> BuilderResult {
+        wrap_in_transaction(build_batch_segment(
+            build_ownership_mask("$ids", self.root_path()?),
+            match self.options.soft_delete {
+                true => format!(
+                    "{}{}; []",
+                    self.build_restrictions("$ids"),
+                    self.build_audited(
+                        "delete",
+                        "$ids",
+                        format!(
+                            "UPDATE $ids SET {0} = time::now() WHERE {0} IS NONE",
+                            DELETED_AT_FIELD
+                        ),
+                    ),
+                ),
+                false => format!(
+                    "{}{}{}; []",
+                    self.build_restrictions("$ids"),
+                    self.build_reference_updates("$ids"),
+                    self.build_audited("delete", "$ids", "DELETE $ids".to_string()),
+                ),
+            },
+        ))
+    }
+
+    /// Brings a soft-deleted record back.
+    ///
+    /// To bind:
+    /// - $id
+    pub fn restore(&self) -> BuilderResult {
+        wrap_in_transaction(build_validation_segment(
+            "$id",
+            self.root_path()?,
+            self.build_audited(
+                "restore",
+                "[$id]",
+                format!(
+                    "UPDATE $id SET {0} = NONE WHERE {0} IS NOT NONE",
+                    DELETED_AT_FIELD
+                ),
             ),
         ))
     }
+
+    /// Lists the soft-deleted records of the user.
+    ///
+    /// To bind:
+    ///
+    pub fn select_deleted(&self) -> BuilderResult {
+        wrap_in_transaction(format!(
+            "SELECT * FROM {} WHERE {} = $user_id AND {} IS NOT NONE",
+            self.table_name,
+            self.root_path()?[0],
+            DELETED_AT_FIELD,
+        ))
+    }
+
+    /// Removes the records soft-deleted longer than `purge_after` ago, regardless of the owner,
+    /// and applies the cascades and cleared references postponed by the soft delete.
+    pub fn purge_deleted(&self) -> BuilderResult {
+        wrap_in_transaction(format!(
+            "LET $purged = (SELECT VALUE id FROM {} WHERE {} IS NOT NONE AND {} < time::now() - {}); \
+            {}DELETE $purged",
+            self.table_name,
+            DELETED_AT_FIELD,
+            DELETED_AT_FIELD,
+            self.options.purge_after,
+            self.build_reference_updates("$purged"),
+        ))
+    }
+
+    /// Lists the changes of a record of an audited table, oldest first. Every entry has
+    /// the `record` id, the `action`, the `before` and `after` states, the `user_id` of the
+    /// acting user and the `timestamp`. Records deleted for good are still checked against
+    /// the ownership path through their last known state.
+    ///
+    /// To bind:
+    /// - $id
+    pub fn select_history(&self) -> BuilderResult {
+        let root_path = self.root_path()?[0];
+        wrap_in_transaction(format!(
+            "SELECT * FROM {}{} WHERE record = $id AND (after.{2} ?? before.{2}) = $user_id ORDER BY timestamp",
+            self.table_name, HISTORY_TABLE_SUFFIX, root_path
+        ))
+    }
+
+    /// Groups the records of the user and aggregates each group into its `value`.
+    ///
+    /// To bind:
+    ///
+    pub fn aggregate(&self, aggregation: &Aggregation) -> BuilderResult {
+        let value = match aggregation.function {
+            AggregateFunction::Sum => format!("math::sum({})", aggregation.field),
+            AggregateFunction::Count => "count()".to_string(),
+            AggregateFunction::Avg => format!("math::mean({})", aggregation.field),
+        };
+        let keys: Vec<(String, &str)> = aggregation
+            .group_by
+            .iter()
+            .map(|group_by| match group_by {
+                GroupBy::Field(field) => (field.to_string(), *field),
+                GroupBy::Date(field, bucket) => (
+                    format!(
+                        "time::group({}, '{}')",
+                        field,
+                        match bucket {
+                            DateBucket::Day => "day",
+                            DateBucket::Month => "month",
+                            DateBucket::Year => "year",
+                        }
+                    ),
+                    *field,
+                ),
+            })
+            .collect();
+        let mut fields: Vec<String> = keys
+            .iter()
+            .map(|(key, field)| match key == field {
+                true => key.clone(),
+                false => format!("{} AS {}", key, field),
+            })
+            .collect();
+        fields.push(format!("{} AS value", value));
+        let group = match keys.is_empty() {
+            true => "GROUP ALL".to_string(),
+            false => format!(
+                "GROUP BY {}",
+                keys.iter()
+                    .map(|(_, field)| *field)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        };
+        wrap_in_transaction(format!(
+            "SELECT {} FROM {} WHERE {} = $user_id{} {}",
+            fields.join(", "),
+            self.table_name,
+            self.root_path()?[0],
+            self.not_deleted("AND"),
+            group,
+        ))
+    }
+
+    /// Defines the full-text indexes of the searchable fields, if they don't exist yet.
+    pub fn define_search(&self) -> String {
+        let mut query = format!(
+            "DEFINE ANALYZER IF NOT EXISTS {} TOKENIZERS class FILTERS lowercase, ascii, snowball(english);",
+            SEARCH_ANALYZER
+        );
+        for field in self.options.searchable {
+            query += &format!(
+                " DEFINE INDEX IF NOT EXISTS {0}_{1}_search ON TABLE {0} FIELDS {1} SEARCH ANALYZER {2} BM25;",
+                self.table_name, field, SEARCH_ANALYZER
+            );
+        }
+        query
+    }
+
+    /// Lists the records of the user matching `$q` in any searchable field, best matches first.
+    /// Every record gets its relevance as `score`.
+    ///
+    /// To bind:
+    /// - $q
+    pub fn search(&self) -> BuilderResult {
+        let fields = self.options.searchable;
+        wrap_in_transaction(format!(
+            "SELECT *, {} AS score FROM {} WHERE ({}) AND {} = $user_id{} ORDER BY score DESC LIMIT {}",
+            (0..fields.len())
+                .map(|i| format!("search::score({})", i))
+                .collect::<Vec<_>>()
+                .join(" + "),
+            self.table_name,
+            fields
+                .iter()
+                .enumerate()
+                .map(|(i, field)| format!("{} @{}@ $q", field, i))
+                .collect::<Vec<_>>()
+                .join(" OR "),
+            self.root_path()?[0],
+            self.not_deleted("AND"),
+            SEARCH_LIMIT,
+        ))
+    }
+
+    /// Subscribes to the changes of the records passing all ownership paths.
+    ///
+    /// Live queries don't see bound parameters, so it runs on the [`crate::UserDb`] of the
+    /// user and checks the paths against `$auth`. Soft-deleted records are left out once
+    /// their deletion is reported.
+    pub fn live_select(&self) -> BuilderResult {
+        if self.paths.is_empty() {
+            return Err(BuilderError::NoOwnershipPaths(self.table_name));
+        }
+        Ok(format!(
+            "LIVE SELECT * FROM {} WHERE {}{}",
+            self.table_name,
+            self.paths
+                .iter()
+                .map(|path| format!("{} = $auth.id", path))
+                .collect::<Vec<_>>()
+                .join(" AND "),
+            match self.options.soft_delete {
+                true => format!(
+                    " AND ({0} IS NONE OR $before.{0} IS NONE)",
+                    DELETED_AT_FIELD
+                ),
+                false => String::new(),
+            },
+        ))
+    }
+
+    /// `ALTER TABLE` statements limiting the records reachable under [`crate::UserDb`] to the
+    /// ones the queries would let through: reads and deletes check the first ownership path,
+    /// writes check all of them. Deleted records are read through `$before`, otherwise their
+    /// deletion isn't reported to live queries. History entries can only be created for the
+    /// acting user and never change.
+    pub fn permissions(&self) -> BuilderResult {
+        let root_path = self.root_path()?[0];
+        let mut query = format!(
+            "ALTER TABLE {} PERMISSIONS FOR select, delete WHERE ({1} ?? $before.{1}) = $auth.id, FOR create, update WHERE {2};",
+            self.table_name,
+            root_path,
+            self.paths
+                .iter()
+                .map(|path| format!("{} = $auth.id", path))
+                .collect::<Vec<_>>()
+                .join(" AND "),
+        );
+        if self.options.audit {
+            query += &format!(
+                " ALTER TABLE {}{} PERMISSIONS FOR select WHERE (after.{2} ?? before.{2}) = $auth.id, \
+                FOR create WHERE user_id = $auth.id, FOR update, delete NONE;",
+                self.table_name, HISTORY_TABLE_SUFFIX, root_path
+            );
+        }
+        Ok(query)
+    }
+
+    /// Inserts `values`, recording them in the history if the table is audited.
+    /// The value of the segment is the array of inserted ids. It is a single statement, a block
+    /// if audited, so that it is the first result of the transaction when no ownership path
+    /// needs to be checked around it.
+    fn build_audited_insert(&self, values: &str) -> String {
+        if !self.options.audit {
+            return format!(
+                "(INSERT INTO {} {} RETURN VALUE id)",
+                self.table_name, values
+            );
+        }
+        format!(
+            "{{ LET $inserted = (INSERT INTO {} {} RETURN AFTER); FOR $record IN $inserted {{ {} }}; $inserted.id }}",
+            self.table_name,
+            values,
+            self.build_history_entry("insert", "NONE", "$record"),
+        )
+    }
+
+    /// Runs `mutation` on the existing records `ids`, recording the ones it changed in the
+    /// history if the table is audited.
+    fn build_audited(&self, action: &str, ids: &str, mutation: String) -> String {
+        if !self.options.audit {
+            return mutation;
+        }
+        format!(
+            "LET $before = (SELECT * FROM {}); {}; FOR $record IN $before {{ \
+            LET $after = (SELECT * FROM ONLY $record.id); IF $record != $after {{ {} }}; }}",
+            ids,
+            mutation,
+            self.build_history_entry(action, "$record", "$after"),
+        )
+    }
+
+    /// `content` with its [`VERSION_FIELD`] set to `version` if the table is versioned.
+    fn with_version(&self, content: &str, version: &str) -> String {
+        match self.options.versioned {
+            true => format!(
+                "object::from_entries(array::concat(object::entries({}), [['{}', {}]]))",
+                content, VERSION_FIELD, version
+            ),
+            false => content.to_string(),
+        }
+    }
+
+    /// `content` without the values of the [`ServerField`]s, which are filled by the server
+    /// instead: from their defaults for a new record, from the stored `record` otherwise.
+    /// Computed fields are evaluated last, over the rest of the content.
+    fn with_server_fields(&self, content: &str, record: Option<&str>) -> String {
+        let server_fields = self.options.server_fields;
+        if server_fields.is_empty() {
+            return content.to_string();
+        }
+        let kept: Vec<String> = server_fields
+            .iter()
+            .filter_map(|field| match (field.value, record) {
+                (
+                    ServerValue::Readonly | ServerValue::Default(_) | ServerValue::Owner,
+                    Some(record),
+                ) => Some(format!("['{0}', {1}.{0}]", field.name, record)),
+                (ServerValue::Default(value), None) => {
+                    Some(format!("['{}', {}]", field.name, value))
+                }
+                (ServerValue::Owner, None) => Some(format!("['{}', $user_id]", field.name)),
+                _ => None,
+            })
+            .collect();
+        let base = format!(
+            "object::from_entries(array::concat(object::entries({})[WHERE $this[0] NOTINSIDE [{}]], [{}]))",
+            content,
+            server_fields
+                .iter()
+                .map(|field| format!("'{}'", field.name))
+                .collect::<Vec<_>>()
+                .join(", "),
+            kept.join(", "),
+        );
+        let computed: Vec<String> = server_fields
+            .iter()
+            .filter_map(|field| match field.value {
+                ServerValue::Computed(expression) => Some(format!(
+                    "['{}', (SELECT VALUE {} FROM ONLY {})]",
+                    field.name, expression, base
+                )),
+                _ => None,
+            })
+            .collect();
+        match computed.is_empty() {
+            true => base,
+            false => format!(
+                "object::from_entries(array::concat(object::entries({}), [{}]))",
+                base,
+                computed.join(", ")
+            ),
+        }
+    }
+
+    /// Throws [`CONFLICT_ERR`] unless `record` is at `version`, if the table is versioned.
+    fn build_version_check(&self, record: &str, version: &str) -> String {
+        match self.options.versioned {
+            true => format!(
+                "IF {}.{} != {} {{ THROW '{}' }};",
+                record, VERSION_FIELD, version, CONFLICT_ERR
+            ),
+            false => String::new(),
+        }
+    }
+
+    /// To bind:
+    /// - $user_id
+    fn build_history_entry(&self, action: &str, before: &str, after: &str) -> String {
+        format!(
+            "CREATE {}{} CONTENT {{ record: ({} ?? {}).id, action: '{}', before: {}, after: {}, user_id: $user_id, timestamp: time::now() }};",
+            self.table_name, HISTORY_TABLE_SUFFIX, after, before, action, before, after
+        )
+    }
+
+    /// Throws [`RESTRICTED_ERR`] if any record references one of the `ids`
+    /// through an [`OnDeleteAction::Restrict`] reference.
+    fn build_restrictions(&self, ids: &str) -> String {
+        self.options
+            .on_delete
+            .iter()
+            .filter(|on_delete| matches!(on_delete.action, OnDeleteAction::Restrict))
+            .map(|on_delete| {
+                format!(
+                    "IF (SELECT VALUE id FROM {0} WHERE {1} IN {2} LIMIT 1) {{ THROW '{3}:{0}.{1}' }};",
+                    on_delete.table, on_delete.field, ids, RESTRICTED_ERR
+                )
+            })
+            .collect()
+    }
+
+    /// Deletes or clears the references to the `ids` according to the `on_delete` rules.
+    fn build_reference_updates(&self, ids: &str) -> String {
+        self.options
+            .on_delete
+            .iter()
+            .filter_map(|on_delete| match on_delete.action {
+                OnDeleteAction::Cascade => Some(format!(
+                    "DELETE {} WHERE {} IN {};",
+                    on_delete.table, on_delete.field, ids
+                )),
+                OnDeleteAction::SetNone => Some(format!(
+                    "UPDATE {0} SET {1} = NONE WHERE {1} IN {2};",
+                    on_delete.table, on_delete.field, ids
+                )),
+                OnDeleteAction::Restrict => None,
+            })
+            .collect()
+    }
 }
 
 /// To bind:
@@ -123,3 +959,93 @@ fn build_validation_segment(key_name: &str, paths: &[&'static str], else_branch:
     }
     result
 }
+
+/// `action` only runs if every item of `ownership_mask` is `true`, and its value is returned as `ids`.
+///
+/// To bind:
+/// - $user_id
+fn build_batch_segment(ownership_mask: String, action: String) -> String {
+    format!(
+        "LET $owned = {ownership_mask}; \
+        LET $affected = IF array::all($owned) {{ {action} }} ELSE {{ [] }}; \
+        RETURN {{ owned: $owned, ids: $affected }}",
+    )
+}
+
+/// Builds an array telling for every item of `items_key` whether it passes all the `paths`.
+///
+/// To bind:
+/// - $user_id
+fn build_ownership_mask(items_key: &str, paths: &[&'static str]) -> String {
+    paths
+        .iter()
+        .map(|path| format!("array::matches({}.{}, $user_id)", items_key, path))
+        .reduce(|mask, next| format!("array::boolean_and({}, {})", mask, next))
+        .unwrap_or_else(|| format!("array::map({}, |$value| true)", items_key))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use surrealdb::sql::{Statement, Value};
+
+    static OWNER_ONLY: QueryBuilder = QueryBuilder::new(
+        "accounts",
+        &["user_id"],
+        None,
+        EntityOptions {
+            audit: true,
+            server_fields: &[ServerField {
+                name: "user_id",
+                value: ServerValue::Owner,
+            }],
+            ..EntityOptions::DEFAULT
+        },
+    );
+
+    /// The statements of `query` between `BEGIN` and `COMMIT`.
+    fn transaction_statements(query: &str) -> Vec<Statement> {
+        let statements = surrealdb::sql::parse(query).unwrap().0 .0;
+        assert!(matches!(statements.first(), Some(Statement::Begin(_))));
+        assert!(matches!(statements.last(), Some(Statement::Commit(_))));
+        statements[1..statements.len() - 1].to_vec()
+    }
+
+    #[test]
+    fn audited_insert_without_content_paths_is_one_statement() {
+        assert!(OWNER_ONLY.content_paths().is_empty());
+        let statements = transaction_statements(&OWNER_ONLY.insert().unwrap());
+        assert_eq!(statements.len(), 1);
+        assert!(
+            matches!(&statements[0], Statement::Value(Value::Block(_))),
+            "{}",
+            statements[0]
+        );
+    }
+
+    #[test]
+    fn audited_insert_batch_is_one_statement() {
+        let statements = transaction_statements(&OWNER_ONLY.insert_batch().unwrap());
+        assert!(matches!(statements.last(), Some(Statement::Output(_))));
+    }
+
+    #[test]
+    fn live_select_skips_soft_deleted_records() {
+        static SOFT_DELETED: QueryBuilder = QueryBuilder::new(
+            "tags",
+            &["account_id.user_id"],
+            None,
+            EntityOptions {
+                soft_delete: true,
+                ..EntityOptions::DEFAULT
+            },
+        );
+        let query = SOFT_DELETED.live_select().unwrap();
+        assert_eq!(
+            query,
+            "LIVE SELECT * FROM tags WHERE account_id.user_id = $auth.id \
+            AND (deleted_at IS NONE OR $before.deleted_at IS NONE)"
+        );
+        assert!(surrealdb::sql::parse(&query).is_ok());
+    }
+}
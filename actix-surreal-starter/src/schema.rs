@@ -0,0 +1,273 @@
+use crate::query_builder::{QueryBuilder, DELETED_AT_FIELD, HISTORY_TABLE_SUFFIX, VERSION_FIELD};
+use crate::{DbAccessConfig, DB};
+use chrono::{DateTime, Utc};
+use std::collections::HashSet;
+use surrealdb::RecordId;
+use thiserror::Error;
+
+/// Table recording the applied [`Migration`]s.
+pub const MIGRATIONS_TABLE: &str = "_migrations";
+
+/// SurrealQL type of a Rust field type, used for the generated `DEFINE FIELD` statements.
+pub trait SurrealType {
+    fn surreal_type() -> String;
+}
+
+macro_rules! impl_surreal_type {
+    ($surreal_type:literal: $($type:ty),*) => {
+        $(
+        impl SurrealType for $type {
+            fn surreal_type() -> String {
+                $surreal_type.to_string()
+            }
+        }
+        )*
+    };
+}
+
+impl_surreal_type!("string": String, &str);
+impl_surreal_type!("bool": bool);
+impl_surreal_type!("int": i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+impl_surreal_type!("float": f32, f64);
+impl_surreal_type!("datetime": DateTime<Utc>, surrealdb::Datetime);
+impl_surreal_type!("record": RecordId);
+impl_surreal_type!("any": serde_json::Value);
+
+impl<T: SurrealType> SurrealType for Option<T> {
+    fn surreal_type() -> String {
+        format!("option<{}>", T::surreal_type())
+    }
+}
+
+impl<T: SurrealType> SurrealType for Vec<T> {
+    fn surreal_type() -> String {
+        format!("array<{}>", T::surreal_type())
+    }
+}
+
+/// A field of an entity deriving `ApiEntity`.
+pub struct FieldSchema {
+    pub name: &'static str,
+    pub surreal_type: String,
+    /// Conditions on `$value` derived from the validators of the field.
+    pub asserts: Vec<&'static str>,
+}
+
+/// `DEFINE` statements for the table of an entity and the fields implied by its options.
+///
+/// Existing definitions are left untouched, changes to them are up to a [`Migration`].
+pub fn entity_schema(query_builder: &QueryBuilder, fields: &[FieldSchema]) -> String {
+    let table = query_builder.table_name;
+    let mut schema = format!("DEFINE TABLE IF NOT EXISTS {} SCHEMAFULL;", table);
+    for field in fields {
+        // Raw identifiers like `r#type` are serialized without their prefix.
+        let name = field.name.trim_start_matches("r#");
+        schema += &define_field(table, name, &field.surreal_type, &field.asserts);
+    }
+    if query_builder.options.soft_delete {
+        schema += &define_field(table, DELETED_AT_FIELD, "option<datetime>", &[]);
+        schema += &define_index(table, DELETED_AT_FIELD, false);
+    }
+    if query_builder.options.versioned {
+        schema += &define_field(table, VERSION_FIELD, "int", &[]);
+    }
+    if query_builder.options.audit {
+        let history = format!("{}{}", table, HISTORY_TABLE_SUFFIX);
+        schema += &format!(" DEFINE TABLE IF NOT EXISTS {} SCHEMAFULL;", history);
+        schema += &define_field(&history, "record", &format!("record<{}>", table), &[]);
+        schema += &define_field(&history, "action", "string", &[]);
+        for state in ["before", "after"] {
+            schema += &format!(
+                " DEFINE FIELD IF NOT EXISTS {} ON TABLE {} FLEXIBLE TYPE option<object>;",
+                state, history
+            );
+        }
+        schema += &define_field(&history, "user_id", "record", &[]);
+        schema += &define_field(&history, "timestamp", "datetime", &[]);
+        schema += &define_index(&history, "record", false);
+    }
+    // Tables without ownership paths keep the default permissions, closed to the users.
+    if let Ok(permissions) = query_builder.permissions() {
+        schema += " ";
+        schema += &permissions;
+    }
+    schema
+}
+
+impl DbAccessConfig {
+    /// `DEFINE` statements for the users and sessions tables used by the authentication.
+    /// The other fields of the users table come from its entity, if declared.
+    pub fn schema(&self) -> String {
+        let users = &self.users;
+        let sessions = &self.sessions;
+        let mut schema = format!(
+            "DEFINE TABLE IF NOT EXISTS {} SCHEMAFULL;",
+            users.table_name
+        );
+        schema += &define_field(users.table_name, users.login, "string", &[]);
+        schema += &define_field(users.table_name, users.password, "string", &[]);
+        schema += &define_index(users.table_name, users.login, true);
+        schema += &format!(
+            " DEFINE TABLE IF NOT EXISTS {} SCHEMAFULL;",
+            sessions.table_name
+        );
+        schema += &define_field(sessions.table_name, sessions.access_token, "string", &[]);
+        schema += &define_field(sessions.table_name, sessions.refresh_token, "string", &[]);
+        // Expirations are bound as RFC 3339 strings.
+        schema += &define_field(
+            sessions.table_name,
+            sessions.access_expiration,
+            "string",
+            &[],
+        );
+        schema += &define_field(
+            sessions.table_name,
+            sessions.refresh_expiration,
+            "string",
+            &[],
+        );
+        schema += &define_field(
+            sessions.table_name,
+            sessions.user_id,
+            &format!("record<{}>", users.table_name),
+            &[],
+        );
+        schema += &define_index(sessions.table_name, sessions.access_token, true);
+        schema += &define_index(sessions.table_name, sessions.refresh_token, true);
+        schema += &define_index(sessions.table_name, sessions.user_id, false);
+        // Sessions are only read through the `DEFINE ACCESS` of the user connections.
+        schema += &format!(
+            " ALTER TABLE {0} PERMISSIONS FOR select WHERE id = $auth.id, FOR create, update, delete NONE; \
+            ALTER TABLE {1} PERMISSIONS NONE;",
+            users.table_name, sessions.table_name
+        );
+        schema
+    }
+}
+
+fn define_field(table: &str, field: &str, surreal_type: &str, asserts: &[&str]) -> String {
+    // `DateTime<Utc>` values arrive as RFC 3339 strings, which `TYPE` checks before any `VALUE`
+    // clause could convert them, so datetime fields are typed by the cast instead.
+    let typing = match surreal_type.contains("datetime") {
+        true => format!("VALUE <{}> $value", surreal_type),
+        false => format!("TYPE {}", surreal_type),
+    };
+    let mut definition = format!(
+        " DEFINE FIELD IF NOT EXISTS {} ON TABLE {} {}",
+        field, table, typing
+    );
+    if !asserts.is_empty() {
+        let condition = asserts
+            .iter()
+            .map(|assert| format!("({})", assert))
+            .collect::<Vec<_>>()
+            .join(" AND ");
+        definition += &match surreal_type.starts_with("option<") {
+            true => format!(" ASSERT $value = NONE OR ({})", condition),
+            false => format!(" ASSERT {}", condition),
+        };
+    }
+    definition + ";"
+}
+
+fn define_index(table: &str, field: &str, unique: bool) -> String {
+    format!(
+        " DEFINE INDEX IF NOT EXISTS {1}_idx ON TABLE {0} COLUMNS {1}{2};",
+        table,
+        field,
+        if unique { " UNIQUE" } else { "" }
+    )
+}
+
+/// A change to the database applied once, in the order of the versions.
+pub struct Migration {
+    pub version: u32,
+    pub name: &'static str,
+    pub query: &'static str,
+}
+
+/// The schema applied by `ServerStarter::start` after connecting to the database.
+#[derive(Default)]
+pub struct SchemaConfig {
+    /// Generated `DEFINE` statements, see `schema()` of `#[derive(ApiEntity)]`.
+    pub entities: String,
+    pub migrations: &'static [Migration],
+}
+
+#[derive(Debug, Error)]
+pub enum MigrationError {
+    #[error("DB query failed: {0}")]
+    Db(#[from] surrealdb::Error),
+    #[error("Migration version {0} is declared more than once")]
+    DuplicateVersion(u32),
+    #[error("Migration {0} ({1}) failed: {2}")]
+    Failed(u32, &'static str, surrealdb::Error),
+}
+
+/// Runs the migrations missing from [`MIGRATIONS_TABLE`], each in its own transaction
+/// together with its record. Returns the versions that were applied.
+pub async fn run_migrations(migrations: &[Migration]) -> Result<Vec<u32>, MigrationError> {
+    let mut versions = HashSet::new();
+    if let Some(migration) = migrations
+        .iter()
+        .find(|migration| !versions.insert(migration.version))
+    {
+        return Err(MigrationError::DuplicateVersion(migration.version));
+    }
+    let applied: HashSet<u32> = DB
+        .query(format!("SELECT VALUE version FROM {}", MIGRATIONS_TABLE))
+        .await?
+        .take::<Vec<u32>>(0)?
+        .into_iter()
+        .collect();
+    let mut pending: Vec<&Migration> = migrations
+        .iter()
+        .filter(|migration| !applied.contains(&migration.version))
+        .collect();
+    pending.sort_by_key(|migration| migration.version);
+    let mut newly_applied = Vec::new();
+    for migration in pending {
+        let failed = |e| MigrationError::Failed(migration.version, migration.name, e);
+        DB.query(format!(
+            "BEGIN TRANSACTION; {}; CREATE {} CONTENT {{ version: $version, name: $name, applied_at: time::now() }}; COMMIT TRANSACTION;",
+            migration.query, MIGRATIONS_TABLE
+        ))
+        .bind(("version", migration.version))
+        .bind(("name", migration.name))
+        .await
+        .map_err(failed)?
+        .check()
+        .map_err(failed)?;
+        newly_applied.push(migration.version);
+    }
+    Ok(newly_applied)
+}
+
+/// Applies the generated `DEFINE` statements.
+pub async fn apply_schema(schema: &str) -> Result<(), surrealdb::Error> {
+    DB.query(schema).await?.check()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn datetime_fields_cast_their_values() {
+        assert_eq!(
+            define_field("loans", "start_date", "datetime", &[]),
+            " DEFINE FIELD IF NOT EXISTS start_date ON TABLE loans VALUE <datetime> $value;"
+        );
+        assert_eq!(
+            define_field("loans", "end_date", "option<datetime>", &[]),
+            " DEFINE FIELD IF NOT EXISTS end_date ON TABLE loans VALUE <option<datetime>> $value;"
+        );
+        assert_eq!(
+            define_field("loans", "principal_amount", "int", &["$value > 0"]),
+            " DEFINE FIELD IF NOT EXISTS principal_amount ON TABLE loans TYPE int ASSERT ($value > 0);"
+        );
+        let definitions = define_field("loans", "end_date", "option<datetime>", &[]);
+        assert!(surrealdb::sql::parse(&definitions).is_ok());
+    }
+}
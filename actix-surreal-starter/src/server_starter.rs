@@ -3,10 +3,18 @@ pub use crate::configuration::*;
 use crate::static_files::{StaticFilesSetupError, StaticFilesSetupHandler};
 
 use crate::authentication::{get_userdata, login, logout, refresh, register};
+use crate::crud_ops::{define_search_indexes, purge_soft_deleted};
+use crate::live::{live, LiveTables, LIVE_ENDPOINT};
+use crate::openapi::{OpenApiConfig, OpenApiType, OPENAPI_ENDPOINT};
+use crate::query_builder::QueryBuilder;
+use crate::schema::{apply_schema, run_migrations, SchemaConfig};
 use crate::server_address::get_server_address;
 use crate::session::cleanup_expired_sessions;
+use crate::user_db::{configure_user_connections, define_user_access, prune_user_connections};
+use crate::validation::PASSWORD_POLICIES_ENDPOINT;
+use actix_surreal_types::PasswordPolicy;
 use actix_web::web::{Json, ServiceConfig};
-use actix_web::{web, App, HttpRequest, HttpServer};
+use actix_web::{web, App, HttpRequest, HttpResponse, HttpServer};
 use colored::Colorize;
 use log::error;
 use serde::de::DeserializeOwned;
@@ -30,14 +38,18 @@ pub trait ServerStarter<TCreds> {
     async fn start<TAppConfig, TRegisterQuery, TRegisterData, TRegisterDataError>(
         names_config: NamesConfig,
         register_config: RegisterConfig<TRegisterQuery, TRegisterData, TRegisterDataError>,
+        query_builders: Vec<&'static QueryBuilder>,
+        schema_config: SchemaConfig,
+        openapi_config: OpenApiConfig,
         app_config: TAppConfig,
     ) -> io::Result<()>
     where
         TAppConfig: Fn(&mut ServiceConfig) -> &mut ServiceConfig + Clone + Send + 'static + Sync,
-        TCreds: DeserializeOwned + 'static + LoginData + Send + Sync,
+        TCreds: DeserializeOwned + 'static + LoginData + Send + Sync + OpenApiType,
         TRegisterQuery: IntoQuery + Clone + Send + Sync + 'static,
-        TRegisterData: Serialize + DeserializeOwned + Send + Sync + LoginData + 'static,
-        TRegisterDataError: 'static + Serialize,
+        TRegisterData:
+            Serialize + DeserializeOwned + Send + Sync + LoginData + 'static + OpenApiType,
+        TRegisterDataError: 'static + Serialize + OpenApiType,
     {
         let NamesConfig {
             env_names_config,
@@ -56,6 +68,32 @@ pub trait ServerStarter<TCreds> {
                 .ok();
         });
         let env_values = EnvValues::new(&env_names_config);
+        if let Ok(path) = env_values.breached_passwords {
+            match PasswordPolicy::load_breached_passwords(path) {
+                Ok(count) => println!(
+                    "{}",
+                    format!("Loaded {} breached passwords.", count).green()
+                ),
+                Err(e) => println!(
+                    "{}",
+                    format!(
+                        "Breached passwords can't be loaded from \"{}\": {}",
+                        path, e
+                    )
+                    .yellow()
+                ),
+            }
+        }
+        let openapi_document = web::Data::new(
+            openapi_config.document::<TCreds, TRegisterData, TRegisterDataError>(&session_config),
+        );
+        let swagger_ui_assets = openapi_config
+            .swagger_ui
+            .as_ref()
+            .map(|swagger_ui| (swagger_ui.assets_path, swagger_ui.assets_dir));
+        let swagger_ui_page = openapi_config
+            .swagger_ui
+            .map(|swagger_ui| (swagger_ui.path, swagger_ui.page()));
         let queries_config = QueriesConfig::get_formatted(&db_access_config);
         to_arc!(
             session_config,
@@ -66,44 +104,131 @@ pub trait ServerStarter<TCreds> {
         );
         let address = get_server_address::<TAppConfig>(&env_values)
             .map_err(|e| io::Error::new(ErrorKind::Other, format!("{0}", e)))?;
+        println!("{}", "Preparing entity queries...".blue());
+        let soft_deletable: Vec<&'static QueryBuilder> = query_builders
+            .iter()
+            .copied()
+            .filter(|query_builder| query_builder.options.soft_delete)
+            .collect();
+        let searchable: Vec<&'static QueryBuilder> = query_builders
+            .iter()
+            .copied()
+            .filter(|query_builder| {
+                !query_builder.options.searchable.is_empty() && !query_builder.paths.is_empty()
+            })
+            .collect();
+        let live_tables = web::Data::new(LiveTables::new(&query_builders));
+        for query_builder in query_builders {
+            if query_builder.paths.is_empty() {
+                println!(
+                    "{}",
+                    format!(
+                        "Notice: table `{}` has no ownership paths, its CRUD queries are not prepared.",
+                        query_builder.table_name
+                    )
+                    .yellow()
+                );
+                continue;
+            }
+            query_builder
+                .prepared()
+                .map_err(|e| io::Error::new(ErrorKind::InvalidData, e.to_string()))?;
+        }
+        println!("{}", "Entity queries prepared.".green());
         println!("{}", "Connecting to the database...".blue());
+        let db_address = *map_var_err!(env_values.db_address, &env_names_config.db_address)?;
+        let db_namespace = *map_var_err!(env_values.db_namespace, &env_names_config.db_namespace)?;
+        let db_name = *map_var_err!(env_values.db_name, &env_names_config.db_name)?;
         db_connect(
-            map_var_err!(env_values.db_address, &env_names_config.db_address)?,
+            db_address,
             map_var_err!(env_values.db_username, &env_names_config.db_username)?,
             map_var_err!(env_values.db_password, &env_names_config.db_password)?,
-            map_var_err!(env_values.db_namespace, &env_names_config.db_namespace)?,
-            map_var_err!(env_values.db_name, &env_names_config.db_name)?,
+            db_namespace,
+            db_name,
         )
         .await?;
+        configure_user_connections(db_address, db_namespace, db_name, &session_config);
         println!("{}", "Database connection established.".green());
+        println!("{}", "Running migrations...".blue());
+        let applied = run_migrations(schema_config.migrations)
+            .await
+            .map_err(|e| io::Error::new(ErrorKind::InvalidData, e.to_string()))?;
+        println!(
+            "{}",
+            format!("Migrations up to date, {} applied.", applied.len()).green()
+        );
+        apply_schema(
+            &(db_access_config.schema()
+                + &define_user_access(&db_access_config, &session_config)
+                + &schema_config.entities),
+        )
+        .await
+        .map_err(|e| io::Error::new(ErrorKind::InvalidData, e.to_string()))?;
+        println!("{}", "Schema applied.".green());
+        if !searchable.is_empty() {
+            println!("{}", "Defining search indexes...".blue());
+            define_search_indexes(&searchable)
+                .await
+                .map_err(|e| io::Error::other(e.to_string()))?;
+            println!("{}", "Search indexes defined.".green());
+        }
         tokio::spawn(cleanup_expired_sessions(queries_config.clone()));
+        tokio::spawn(prune_user_connections());
+        if !soft_deletable.is_empty() {
+            tokio::spawn(purge_soft_deleted(soft_deletable));
+        }
 
         std::panic::set_hook(Box::new(|panic_info| {
             println!("{}", format!("Panic occurred: {:?}", panic_info).red());
         }));
-        let static_files_setup_handler: Arc<dyn Fn(&mut ServiceConfig) + Send + Sync> =
-            match StaticFilesSetupHandler::new(&env_values, &env_names_config) {
-                Ok(handler) => {
-                    let handler = Arc::new(handler);
-                    handler.output_errors();
-                    Arc::new(move |cfg| handler.clone().config(cfg))
-                }
-                Err(e) => {
-                    match e {
-                        StaticFilesSetupError::EnvNotSet(_, _) => {
-                            println!("{}", e.to_string().blue())
-                        }
-                        _ => println!("{}", e.to_string().yellow()),
+        let mut handler = StaticFilesSetupHandler::new(&env_values, &env_names_config)
+            .unwrap_or_else(|e| {
+                match e {
+                    StaticFilesSetupError::EnvNotSet(_, _) => {
+                        println!("{}", e.to_string().blue())
                     }
-                    Arc::new(|_| {})
+                    _ => println!("{}", e.to_string().yellow()),
                 }
-            };
+                StaticFilesSetupHandler::default()
+            });
+        if let Some((assets_path, assets_dir)) = swagger_ui_assets {
+            handler.add_endpoint(assets_path, assets_dir, false);
+        }
+        handler.output_errors();
+        let handler = Arc::new(handler);
+        let static_files_setup_handler: Arc<dyn Fn(&mut ServiceConfig) + Send + Sync> =
+            Arc::new(move |cfg| handler.clone().config(cfg));
 
         let server = HttpServer::new(move || {
             let queries_config = queries_config.clone();
             let session_config = session_config.clone();
             let register_config = register_config.clone();
+            let swagger_ui_page = swagger_ui_page.clone();
             App::new()
+                .app_data(live_tables.clone())
+                .app_data(openapi_document.clone())
+                .route(LIVE_ENDPOINT, web::get().to(live))
+                .route(
+                    OPENAPI_ENDPOINT,
+                    web::get().to(|document: web::Data<serde_json::Value>| async move {
+                        HttpResponse::Ok().json(document.get_ref())
+                    }),
+                )
+                .route(
+                    PASSWORD_POLICIES_ENDPOINT,
+                    web::get().to(|| async { HttpResponse::Ok().json(PasswordPolicy::installed()) }),
+                )
+                .configure(move |cfg| {
+                    if let Some((path, page)) = swagger_ui_page {
+                        cfg.route(
+                            path,
+                            web::get().to(move || {
+                                let page = page.clone();
+                                async move { HttpResponse::Ok().content_type("text/html").body(page) }
+                            }),
+                        );
+                    }
+                })
                 .route(
                     "/login",
                     web::post().to(
@@ -34,6 +34,7 @@ struct StaticEndpointConfig {
     use_index: bool,
 }
 
+#[derive(Default)]
 pub struct StaticFilesSetupHandler {
     endpoints: Vec<StaticEndpointConfig>,
     static_file_errors: Vec<StaticFilesSetupError>,
@@ -58,38 +59,9 @@ impl StaticFilesSetupHandler {
         let mut endpoints: Vec<StaticEndpointConfig> = serde_json::from_str(&config_text)
             .map_err(|e| StaticFilesSetupError::ConfigFileParsingConfig(e))?;
         let mut errors: Vec<StaticFilesSetupError> = Vec::new();
-        fn get_path_not_found_error(path: &Path) -> StaticFilesSetupError {
-            let path = path.canonicalize().map_err(|e| {
-                StaticFilesSetupError::CanonizationError(path.to_string_lossy().to_string(), e)
-            });
-            match path {
-                Ok(path) => {
-                    let path_str = path.display().to_string();
-                    EntityNotFound(
-                        path_str
-                            .strip_prefix(r"\\?\")
-                            .unwrap_or(&path_str)
-                            .to_string(),
-                    )
-                }
-                Err(e) => e,
-            }
-        }
         endpoints = endpoints
             .into_iter()
-            .filter_map(|e| {
-                let path = Path::new(&e.dir);
-                if !path.exists() {
-                    errors.push(get_path_not_found_error(path));
-                    return None;
-                }
-                let path = path.join("index.html");
-                if e.use_index && !path.exists() {
-                    errors.push(get_path_not_found_error(&path));
-                    return None;
-                }
-                Some(e)
-            })
+            .filter_map(|e| check_endpoint(e, &mut errors))
             .collect();
 
         Ok(Self {
@@ -98,6 +70,19 @@ impl StaticFilesSetupHandler {
         })
     }
 
+    /// Serves `dir` at `mount_path` ahead of the configured endpoints, e.g. the assets of the
+    /// Swagger UI. A missing directory is reported by `output_errors` like the configured ones.
+    pub fn add_endpoint(&mut self, mount_path: &str, dir: &str, use_index: bool) {
+        let endpoint = StaticEndpointConfig {
+            mount_path: mount_path.to_string(),
+            dir: dir.to_string(),
+            use_index,
+        };
+        if let Some(endpoint) = check_endpoint(endpoint, &mut self.static_file_errors) {
+            self.endpoints.insert(0, endpoint);
+        }
+    }
+
     pub fn output_errors(&self) {
         self.static_file_errors
             .iter()
@@ -116,6 +101,41 @@ impl StaticFilesSetupHandler {
     }
 }
 
+fn check_endpoint(
+    endpoint: StaticEndpointConfig,
+    errors: &mut Vec<StaticFilesSetupError>,
+) -> Option<StaticEndpointConfig> {
+    let path = Path::new(&endpoint.dir);
+    if !path.exists() {
+        errors.push(get_path_not_found_error(path));
+        return None;
+    }
+    let path = path.join("index.html");
+    if endpoint.use_index && !path.exists() {
+        errors.push(get_path_not_found_error(&path));
+        return None;
+    }
+    Some(endpoint)
+}
+
+fn get_path_not_found_error(path: &Path) -> StaticFilesSetupError {
+    let path = path.canonicalize().map_err(|e| {
+        StaticFilesSetupError::CanonizationError(path.to_string_lossy().to_string(), e)
+    });
+    match path {
+        Ok(path) => {
+            let path_str = path.display().to_string();
+            EntityNotFound(
+                path_str
+                    .strip_prefix(r"\\?\")
+                    .unwrap_or(&path_str)
+                    .to_string(),
+            )
+        }
+        Err(e) => e,
+    }
+}
+
 pub fn serve_files_with_index(mount_path: &str, dir: &str) -> Files {
     serve_files_with_config(mount_path, dir, |f| f.index_file("index.html"))
 }
@@ -0,0 +1,151 @@
+use crate::{DbAccessConfig, SessionConfig};
+use actix_surreal_types::{ClientError, Error, ServerError};
+use actix_web::{FromRequest, HttpRequest};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{LazyLock, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+use surrealdb::engine::remote::ws::{Client, Ws};
+use surrealdb::opt::auth::Record;
+use surrealdb::{RecordId, Surreal};
+
+/// Record access the connections of the users sign in with, using their access token.
+pub const USER_ACCESS: &str = "api_users";
+
+/// A database connection signed in as the user of the request through [`USER_ACCESS`].
+///
+/// The CRUD queries run on it, so the table permissions generated from the ownership
+/// paths apply on top of the checks of the queries themselves.
+#[derive(Clone)]
+pub struct UserDb {
+    pub user_id: RecordId,
+    pub db: Surreal<Client>,
+}
+
+struct UserDbConfig {
+    address: &'static str,
+    namespace: &'static str,
+    database: &'static str,
+    access_token_cookie_name: &'static str,
+    lifetime: Duration,
+}
+
+static USER_DB_CONFIG: OnceLock<UserDbConfig> = OnceLock::new();
+
+/// Connections by access token, reused until the token expires.
+static USER_CONNECTIONS: LazyLock<Mutex<HashMap<String, (Instant, UserDb)>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+#[derive(Serialize)]
+struct AccessParams {
+    access_token: String,
+}
+
+/// Called once the root connection is established.
+pub(crate) fn configure_user_connections(
+    address: &'static str,
+    namespace: &'static str,
+    database: &'static str,
+    session_config: &SessionConfig,
+) {
+    let _ = USER_DB_CONFIG.set(UserDbConfig {
+        address,
+        namespace,
+        database,
+        access_token_cookie_name: session_config.access_token_cookie_name,
+        lifetime: session_config.access_token_expiration.unsigned_abs(),
+    });
+}
+
+/// `DEFINE ACCESS` statement signing in the user of an unexpired session by its access token.
+pub(crate) fn define_user_access(
+    db_access_config: &DbAccessConfig,
+    session_config: &SessionConfig,
+) -> String {
+    let sessions = &db_access_config.sessions;
+    format!(
+        " DEFINE ACCESS OVERWRITE {} ON DATABASE TYPE RECORD \
+        SIGNIN (SELECT VALUE {} FROM ONLY {} WHERE {} = $access_token AND <datetime> {} > time::now() LIMIT 1) \
+        DURATION FOR SESSION {}m;",
+        USER_ACCESS,
+        sessions.user_id,
+        sessions.table_name,
+        sessions.access_token,
+        sessions.access_expiration,
+        session_config.access_token_expiration.whole_minutes(),
+    )
+}
+
+/// Drops the connection of a logged out or refreshed session.
+pub(crate) fn forget_user_connection(access_token: &str) {
+    if let Ok(mut connections) = USER_CONNECTIONS.lock() {
+        connections.remove(access_token);
+    }
+}
+
+/// Periodically drops the connections whose access token has expired, closing them.
+pub async fn prune_user_connections() -> ! {
+    let mut interval = tokio::time::interval(Duration::from_secs(60));
+
+    loop {
+        interval.tick().await;
+        if let Ok(mut connections) = USER_CONNECTIONS.lock() {
+            let now = Instant::now();
+            connections.retain(|_, (expiration, _)| *expiration > now);
+        }
+    }
+}
+
+async fn user_db(access_token: String) -> Result<UserDb, Error> {
+    let config = USER_DB_CONFIG.get().ok_or(ServerError::Db(
+        "user connections are not configured".to_string(),
+    ))?;
+    if let Some((_, user_db)) = USER_CONNECTIONS
+        .lock()
+        .ok()
+        .and_then(|connections| connections.get(&access_token).cloned())
+        .filter(|(expiration, _)| *expiration > Instant::now())
+    {
+        return Ok(user_db);
+    }
+    let db = Surreal::new::<Ws>(config.address).await?;
+    db.signin(Record {
+        namespace: config.namespace,
+        database: config.database,
+        access: USER_ACCESS,
+        params: AccessParams {
+            access_token: access_token.clone(),
+        },
+    })
+    .await
+    .map_err(|_| ClientError::InvalidAccessToken)?;
+    db.use_ns(config.namespace).use_db(config.database).await?;
+    let user_id = db
+        .query("RETURN $auth.id")
+        .await?
+        .take::<Option<RecordId>>(0)?
+        .ok_or(ClientError::InvalidAccessToken)?;
+    let user_db = UserDb { user_id, db };
+    if let Ok(mut connections) = USER_CONNECTIONS.lock() {
+        connections.insert(
+            access_token,
+            (Instant::now() + config.lifetime, user_db.clone()),
+        );
+    }
+    Ok(user_db)
+}
+
+impl FromRequest for UserDb {
+    type Error = Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self, Self::Error>>>>;
+
+    fn from_request(http_request: &HttpRequest, _: &mut actix_web::dev::Payload) -> Self::Future {
+        let access_token = USER_DB_CONFIG
+            .get()
+            .and_then(|config| http_request.cookie(config.access_token_cookie_name))
+            .map(|cookie| cookie.value().to_string());
+        Box::pin(async move { user_db(access_token.ok_or(ClientError::NoAccessToken)?).await })
+    }
+}
@@ -0,0 +1,83 @@
+use crate::{UserDb, DB};
+use serde::Serialize;
+use surrealdb::engine::remote::ws::Client;
+use surrealdb::{RecordId, Surreal};
+
+/// Lists the installed `PasswordPolicy`s by name, for the clients to check passwords with the
+/// rules of the server.
+pub const PASSWORD_POLICIES_ENDPOINT: &str = "/password_policies";
+
+/// What the async validators of `impl_validators!` get to check values against the database,
+/// listed as `async(validator)` among the validators of `#[derive(ApiEntity)]`.
+#[derive(Clone, Copy)]
+pub struct ValidationContext<'a> {
+    /// The connection of the user, with the table permissions applied, or the root connection
+    /// when there is no user yet, e.g. on registration.
+    pub db: &'a Surreal<Client>,
+    pub user_id: Option<&'a RecordId>,
+    /// The record being updated, which `is_taken` leaves out.
+    pub record_id: Option<&'a RecordId>,
+}
+
+impl<'a> ValidationContext<'a> {
+    pub fn of_user(user: &'a UserDb) -> Self {
+        Self {
+            db: &user.db,
+            user_id: Some(&user.user_id),
+            record_id: None,
+        }
+    }
+
+    pub fn anonymous() -> ValidationContext<'static> {
+        ValidationContext {
+            db: &DB,
+            user_id: None,
+            record_id: None,
+        }
+    }
+
+    /// The context of an update of `record_id`.
+    pub fn updating(self, record_id: &'a RecordId) -> Self {
+        Self {
+            record_id: Some(record_id),
+            ..self
+        }
+    }
+
+    /// Whether the record exists, regardless of the permissions of the user since it may
+    /// belong to a table shared by all users. Failing queries count as missing records.
+    pub async fn exists(&self, id: &RecordId) -> bool {
+        DB.query("RETURN record::exists($id)")
+            .bind(("id", id.clone()))
+            .await
+            .ok()
+            .and_then(|mut response| response.take::<Option<bool>>(0).ok().flatten())
+            .unwrap_or(false)
+    }
+
+    /// Whether a record of `table` the user can see has `field` set to `value`, e.g. to keep
+    /// values unique per user. The record being updated doesn't count. Failing queries count
+    /// as taken values.
+    pub async fn is_taken(
+        &self,
+        table: &str,
+        field: &str,
+        value: impl Serialize + 'static,
+    ) -> bool {
+        self.db
+            .query(
+                "RETURN (SELECT VALUE id FROM type::table($table) \
+                WHERE type::field($field) = $value AND id != $record LIMIT 1) != []",
+            )
+            .bind(("table", table.to_string()))
+            .bind(("field", field.to_string()))
+            .bind(("value", value))
+            .bind(("record", self.record_id.cloned()))
+            .await
+            .ok()
+            .and_then(|mut response| response.take::<Option<bool>>(0).ok().flatten())
+            .unwrap_or(true)
+    }
+}
+
+pub use actix_surreal_types::validators::{ComparedFields, ItemError};
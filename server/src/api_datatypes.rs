@@ -1,209 +1,396 @@
 use actix_surreal_starter::pre_built::validators::*;
-use actix_surreal_starter_macros::{api_entities, impl_display_for_error};
+use actix_surreal_starter::query_builder::{Aggregation, DateBucket, GroupBy, OnDelete};
+use actix_surreal_starter::{ApiEntity, EntityRegistry};
+use actix_surreal_starter_macros::{impl_display_for_error, impl_validators};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::sync::LazyLock;
 use surrealdb::RecordId;
 use thiserror::Error;
 
+/// Serialized in the shape of `ValidationError`, so that the clients render both the same way.
 #[derive(Debug, Error, Serialize, Deserialize, Clone)]
+#[serde(tag = "code", content = "params")]
 pub enum ApiValidationError {
+    TransferToSameAccount,
+    TransferAmountsMismatch,
+    UsernameTaken,
+    RecordNotFound,
+    TransactionAlreadyLinked,
+    #[serde(untagged)]
     DefaultError(#[from] ValidationError),
 }
 
 #[allow(dead_code)]
 struct Validator;
 impl DefaultValidations for Validator {}
+impl EntityValidations for Validator {}
 
 impl_display_for_error!(ApiValidationError);
 
-api_entities!(
-    validator: Validator,
-    error: ApiValidationError,
-    // TODO: remove unnecessary fields from the user api, leaving them only for authorization and registration
-    User|UserError("users") {
-        email: String [email_format],
-        username: String [not_empty],
-        password: String [password_basic],
-        registration_date: DateTime<Utc>,
-        selected_preference: Option<String>,
-    }
-    // TODO: Validate other fields like currency_id. It appears that by default surrealDB allows adding non-existing ids freely
-    Account|AccountError("accounts" ["user_id"]) {
-        title: String [not_empty],
-        user_id: RecordId,
-        currency_id: RecordId,
-        balance: i64,
+impl_validators! {
+    (trait_name: EntityValidations, parameter_name: v, context_name: context, error: ApiValidationError) {
+        different_accounts(&RecordId, &RecordId) {
+            v.0 == v.1 => TransferToSameAccount
+        }
+        transfer_amounts(&Transfer) {
+            (v.amount_from as f64 * v.conversion_rate).round() as i64 != v.amount_to => TransferAmountsMismatch
+        }
+        async username_available(&str) {
+            context.is_taken("users", "username", v.to_string()).await => UsernameTaken
+        }
+        async record_exists(&RecordId) {
+            !context.exists(v).await => RecordNotFound
+        }
+        async transaction_not_linked(&RecordId) {
+            context.is_taken("loan_payments", "transaction_id", v.clone()).await => TransactionAlreadyLinked
+        }
     }
+}
 
-    Register|RegisterError("register") {
-        username: String [not_empty],
-        email: String [email_format],
-        password: String [password_basic],
-    }
+// TODO: remove unnecessary fields from the user api, leaving them only for authorization and registration
+#[derive(Debug, Serialize, Deserialize, Clone, ApiEntity)]
+#[api(table = "users")]
+pub struct User {
+    #[validate(email_format)]
+    pub email: String,
+    #[validate(not_empty)]
+    pub username: String,
+    #[validate(
+        password("users"),
+        password_unlike("users", email),
+        password_unlike("users", username)
+    )]
+    pub password: String,
+    #[validate(server_default(now))]
+    pub registration_date: DateTime<Utc>,
+    pub selected_preference: Option<String>,
+}
 
-    Creds|CredsError("creds") {
-        email: String,
-        password: String,
-    }
+// TODO: Validate the other ids as well. It appears that by default surrealDB allows adding non-existing ids freely
+#[derive(Debug, Serialize, Deserialize, Clone, ApiEntity)]
+#[api(table = "accounts", owner_paths = ["user_id"], soft_delete, audit, on_delete = &[
+    OnDelete::cascade("transactions", "account_id"),
+    OnDelete::cascade("auto_distributions", "account_id"),
+    OnDelete::restrict("transfers", "account_from"),
+    OnDelete::restrict("transfers", "account_to"),
+])]
+pub struct Account {
+    #[validate(not_empty)]
+    pub title: String,
+    #[validate(owner)]
+    pub user_id: RecordId,
+    #[validate(async(record_exists))]
+    pub currency_id: RecordId,
+    #[validate(server_default(0))]
+    pub balance: i64,
+}
 
-    Tag|TagError("tags" ["user_id", "metadata_id.user_id"]) {
-        user_id: RecordId,
-        metadata_id: RecordId,
-    }
+#[derive(Debug, Serialize, Deserialize, Clone, ApiEntity)]
+#[api(table = "register", no_table)]
+pub struct Register {
+    #[validate(not_empty, async(username_available))]
+    pub username: String,
+    #[validate(email_format)]
+    pub email: String,
+    #[validate(
+        password("users"),
+        password_unlike("users", email),
+        password_unlike("users", username)
+    )]
+    pub password: String,
+}
 
-    MetadataTag|MetadataTagError("metadata_tags" ["metadata_id.user_id"]) {
-        metadata_id: RecordId,
-        tag_id: RecordId,
-        exception: bool,
-    }
+#[derive(Debug, Serialize, Deserialize, Clone, ApiEntity)]
+#[api(table = "creds", no_table)]
+pub struct Creds {
+    pub email: String,
+    pub password: String,
+}
 
-    // TODO: make paths work properly with Options
-    TagGroup|TagGroupError("tag_groups" ["user_id", "metadata_id.user_id"]) {
-        user_id: RecordId,
-        metadata_id: Option<RecordId>,
-    }
+#[derive(Debug, Serialize, Deserialize, Clone, ApiEntity)]
+#[api(table = "tags", owner_paths = ["user_id", "metadata_id.user_id"])]
+pub struct Tag {
+    #[validate(owner)]
+    pub user_id: RecordId,
+    pub metadata_id: RecordId,
+}
 
-    TagGroupTag|TagGroupTagError("tag_group_tags" ["tag_group_id.user_id", "tag_id.user_id"]) {
-        tag_group_id: RecordId,
-        tag_id: RecordId,
-    }
+#[derive(Debug, Serialize, Deserialize, Clone, ApiEntity)]
+#[api(table = "metadata_tags", owner_paths = ["metadata_id.user_id"])]
+pub struct MetadataTag {
+    pub metadata_id: RecordId,
+    pub tag_id: RecordId,
+    pub exception: bool,
+}
 
-    MetadataTagGroup|MetadataTagGroupError("metadata_tag_groups" ["metadata_id.user_id", "tag_group_id.user_id"]) {
-        metadata_id: RecordId,
-        tag_group_id: RecordId,
-    }
+// TODO: make paths work properly with Options
+#[derive(Debug, Serialize, Deserialize, Clone, ApiEntity)]
+#[api(table = "tag_groups", owner_paths = ["user_id", "metadata_id.user_id"])]
+pub struct TagGroup {
+    #[validate(owner)]
+    pub user_id: RecordId,
+    pub metadata_id: Option<RecordId>,
+}
 
-    FinancialGoal|FinancialGoalError("financial_goals" ["user_id", "metadata_id.user_id"]) {
-        user_id: RecordId,
-        currency_id: RecordId,
-        start_date: DateTime<Utc> [v1_gt_v2(end_date)],
-        end_date: DateTime<Utc>,
-        target_income: i64 [gt_zero],
-        metadata_id: RecordId,
-    }
+#[derive(Debug, Serialize, Deserialize, Clone, ApiEntity)]
+#[api(table = "tag_group_tags", owner_paths = ["tag_group_id.user_id", "tag_id.user_id"])]
+pub struct TagGroupTag {
+    pub tag_group_id: RecordId,
+    pub tag_id: RecordId,
+}
 
-    Transaction|TransactionError("transactions" ["account_id.user_id", "metadata_id.user_id"]) {
-        account_id: RecordId,
-        amount: i64,
-        date: DateTime<Utc>,
-        metadata_id: RecordId,
-    }
+#[derive(Debug, Serialize, Deserialize, Clone, ApiEntity)]
+#[api(table = "metadata_tag_groups", owner_paths = ["metadata_id.user_id", "tag_group_id.user_id"])]
+pub struct MetadataTagGroup {
+    pub metadata_id: RecordId,
+    pub tag_group_id: RecordId,
+}
 
-    Transfer|TransferError("transfers" ["account_from.user_id", "metadata_id.user_id"]) {
-        account_from: String,
-        account_to: String,
-        amount_from: i64 [gt_zero],
-        amount_to: i64 [gt_zero],
-        conversion_rate: f64 [gt_zero],
-        fee: f64 [gt_zero],
-        metadata_id: RecordId,
-    }
+#[derive(Debug, Serialize, Deserialize, Clone, ApiEntity)]
+#[api(table = "financial_goals", owner_paths = ["user_id", "metadata_id.user_id"], versioned)]
+pub struct FinancialGoal {
+    #[validate(owner)]
+    pub user_id: RecordId,
+    pub currency_id: RecordId,
+    #[validate(v1_gt_v2(end_date))]
+    pub start_date: DateTime<Utc>,
+    pub end_date: DateTime<Utc>,
+    #[validate(gt_zero)]
+    pub target_income: i64,
+    pub metadata_id: RecordId,
+    /// Set by the queries, see `EntityOptions::versioned`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<u64>,
+}
 
-    StableIncome|StableIncomeError("stable_incomes" ["user_id", "metadata_id.user_id"]) {
-        user_id: RecordId,
-        currency_id: RecordId,
-        amount_per_month: i64 [ne_zero],
-        start_date: DateTime<Utc> [optional_v2_gt_v1(end_date)],
-        end_date: Option<DateTime<Utc>>,
-        last_update_date: DateTime<Utc>,
-        metadata_id: RecordId,
-    }
+#[derive(Debug, Serialize, Deserialize, Clone, ApiEntity)]
+#[api(table = "transactions", owner_paths = ["account_id.user_id", "metadata_id.user_id"], audit, aggregations = &[
+    Aggregation::sum("monthly_amount_by_account", "amount", &[
+        GroupBy::Field("account_id"),
+        GroupBy::Date("date", DateBucket::Month),
+]),
+])]
+pub struct Transaction {
+    pub account_id: RecordId,
+    pub amount: i64,
+    pub date: DateTime<Utc>,
+    pub metadata_id: RecordId,
+}
 
-    StableIncomeIncome|StableIncomeIncomeError("stable_income_incomes" ["stable_income_id.user_id", "transaction_id.user_id"]) {
-        stable_income_id: RecordId,
-        transaction_id: RecordId,
-    }
+#[derive(Debug, Serialize, Deserialize, Clone, ApiEntity)]
+#[api(table = "transfers", owner_paths = ["account_from.user_id", "metadata_id.user_id"], audit)]
+#[validate(different_accounts(account_from, account_to), transfer_amounts)]
+pub struct Transfer {
+    pub account_from: RecordId,
+    pub account_to: RecordId,
+    #[validate(gt_zero)]
+    pub amount_from: i64,
+    #[validate(gt_zero)]
+    pub amount_to: i64,
+    #[validate(finite, gt_zero)]
+    pub conversion_rate: f64,
+    #[validate(finite, gt_zero)]
+    pub fee: f64,
+    pub metadata_id: RecordId,
+}
 
-    Loan|LoanError("loans" ["user_id", "metadata_id.user_id"]) {
-        user_id: RecordId,
-        currency_id: RecordId,
-        principal_amount: i64 [gt_zero],
-        interest_rate: f64 [gt_zero],
-        start_date: DateTime<Utc> [optional_v2_gt_v1(end_date)],
-        end_date: Option<DateTime<Utc>>,
-        interest_rate_type: String,
-        compounding_frequency: String,
-        metadata_id: RecordId,
-    }
+#[derive(Debug, Serialize, Deserialize, Clone, ApiEntity)]
+#[api(table = "stable_incomes", owner_paths = ["user_id", "metadata_id.user_id"])]
+pub struct StableIncome {
+    #[validate(owner)]
+    pub user_id: RecordId,
+    pub currency_id: RecordId,
+    #[validate(ne_zero)]
+    pub amount_per_month: i64,
+    #[validate(optional_v2_gt_v1(end_date))]
+    pub start_date: DateTime<Utc>,
+    pub end_date: Option<DateTime<Utc>>,
+    #[validate(computed("time::now()"))]
+    pub last_update_date: DateTime<Utc>,
+    pub metadata_id: RecordId,
+}
 
-    LoanPayment|LoanPaymentError("loan_payments" ["loan_id.user_id", "transaction_id.user_id"]) {
-        loan_id: RecordId,
-        transaction_id: RecordId,
-    }
+#[derive(Debug, Serialize, Deserialize, Clone, ApiEntity)]
+#[api(table = "stable_income_incomes", owner_paths = ["stable_income_id.user_id", "transaction_id.user_id"])]
+pub struct StableIncomeIncome {
+    pub stable_income_id: RecordId,
+    pub transaction_id: RecordId,
+}
 
-    Investment|InvestmentError("investments" ["user_id", "metadata_id.user_id"]) {
-        user_id: RecordId,
-        currency_id: RecordId,
-        r#type: String,
-        compounding_frequency: String,
-        principal_amount: i64 [gt_zero],
-        start_date: DateTime<Utc> [optional_v2_gt_v1(expected_end_date), optional_v2_gt_v1(end_date)],
-        expected_end_date: Option<DateTime<Utc>> ,
-        end_date: Option<DateTime<Utc>>,
-        risk_level: String,
-        expected_return: f64 [gt_zero],
-        metadata_id: RecordId,
-    }
+#[derive(Debug, Serialize, Deserialize, Clone, ApiEntity)]
+#[api(table = "loans", owner_paths = ["user_id", "metadata_id.user_id"], soft_delete, audit, on_delete = &[
+    OnDelete::cascade("loan_payments", "loan_id"),
+    OnDelete::cascade("loan_auto_distributions", "loan_id"),
+])]
+pub struct Loan {
+    #[validate(owner)]
+    pub user_id: RecordId,
+    pub currency_id: RecordId,
+    #[validate(gt_zero)]
+    pub principal_amount: i64,
+    #[validate(finite, gt_zero)]
+    pub interest_rate: f64,
+    #[validate(optional_v2_gt_v1(end_date))]
+    pub start_date: DateTime<Utc>,
+    pub end_date: Option<DateTime<Utc>>,
+    #[validate(one_of(["simple", "compound"]))]
+    pub interest_rate_type: String,
+    #[validate(one_of(["annually", "semi_annually", "quarterly", "monthly", "daily"]))]
+    pub compounding_frequency: String,
+    pub metadata_id: RecordId,
+}
 
-    InvestmentReturn|InvestmentReturnError("investment_returns" ["investment_id.user_id", "transaction_id.user_id"]) {
-        investment_id: RecordId,
-        transaction_id: RecordId,
-    }
+#[derive(Debug, Serialize, Deserialize, Clone, ApiEntity)]
+#[api(table = "loan_payments", owner_paths = ["loan_id.user_id", "transaction_id.user_id"])]
+pub struct LoanPayment {
+    pub loan_id: RecordId,
+    #[validate(async(transaction_not_linked))]
+    pub transaction_id: RecordId,
+}
 
-    Metadata|MetadataError("metadata" ["user_id"]) {
-        title: Option<String>,
-        description: Option<String>,
-        user_id: RecordId,
-    }
+#[derive(Debug, Serialize, Deserialize, Clone, ApiEntity)]
+#[api(table = "investments", owner_paths = ["user_id", "metadata_id.user_id"])]
+pub struct Investment {
+    #[validate(owner)]
+    pub user_id: RecordId,
+    pub currency_id: RecordId,
+    pub r#type: String,
+    #[validate(one_of(["annually", "semi_annually", "quarterly", "monthly", "daily"]))]
+    pub compounding_frequency: String,
+    #[validate(gt_zero)]
+    pub principal_amount: i64,
+    #[validate(optional_v2_gt_v1(expected_end_date), optional_v2_gt_v1(end_date))]
+    pub start_date: DateTime<Utc>,
+    pub expected_end_date: Option<DateTime<Utc>>,
+    pub end_date: Option<DateTime<Utc>>,
+    #[validate(one_of(["low", "moderate", "high", "very_high"]))]
+    pub risk_level: String,
+    #[validate(finite, gt_zero)]
+    pub expected_return: f64,
+    pub metadata_id: RecordId,
+}
 
-    Preference|PreferenceError("preferences" ["user_id"]) {
-        user_id: RecordId,
-        default_currency_id: RecordId,
-        language: String,
-    }
+#[derive(Debug, Serialize, Deserialize, Clone, ApiEntity)]
+#[api(table = "investment_returns", owner_paths = ["investment_id.user_id", "transaction_id.user_id"])]
+pub struct InvestmentReturn {
+    pub investment_id: RecordId,
+    pub transaction_id: RecordId,
+}
 
-    AutoDistribution|AutoDistributionError("auto_distributions" ["account_id.user_id", "metadata_id.user_id"]) {
-        ratio: f64 [gt_zero],
-        account_id: RecordId,
-        metadata_id: RecordId,
-        record_metadata_id: RecordId,
-    }
+#[derive(Debug, Serialize, Deserialize, Clone, ApiEntity)]
+#[api(table = "metadata", owner_paths = ["user_id"])]
+pub struct Metadata {
+    #[validate(searchable)]
+    pub title: Option<String>,
+    #[validate(searchable)]
+    pub description: Option<String>,
+    #[validate(owner)]
+    pub user_id: RecordId,
+}
 
-    FinancialGoalAutoDistribution|FinancialGoalAutoDistributionError("financial_goal_auto_distributions" ["financial_goal_id.user_id", "auto_distribution_id.account_id.user_id"]) {
-        financial_goal_id: RecordId,
-        auto_distribution_id: RecordId,
-    }
+#[derive(Debug, Serialize, Deserialize, Clone, ApiEntity)]
+#[api(table = "preferences", owner_paths = ["user_id"])]
+pub struct Preference {
+    #[validate(owner)]
+    pub user_id: RecordId,
+    pub default_currency_id: RecordId,
+    #[validate(bcp47_language)]
+    pub language: String,
+}
 
-    StableIncomeAutoDistribution|StableIncomeAutoDistributionError("stable_income_auto_distributions" ["stable_income_id.user_id", "auto_distribution_id.account_id.user_id"]) {
-        stable_income_id: RecordId,
-        auto_distribution_id: RecordId,
-    }
+#[derive(Debug, Serialize, Deserialize, Clone, ApiEntity)]
+#[api(table = "auto_distributions", owner_paths = ["account_id.user_id", "metadata_id.user_id"])]
+pub struct AutoDistribution {
+    #[validate(finite, gt_zero)]
+    pub ratio: f64,
+    pub account_id: RecordId,
+    pub metadata_id: RecordId,
+    pub record_metadata_id: RecordId,
+}
 
-    LoanAutoDistribution|LoanAutoDistributionError("loan_auto_distributions" ["loan_id.user_id", "auto_distribution_id.account_id.user_id"]) {
-        loan_id: RecordId,
-        auto_distribution_id: RecordId,
-    }
+#[derive(Debug, Serialize, Deserialize, Clone, ApiEntity)]
+#[api(table = "financial_goal_auto_distributions", owner_paths = ["financial_goal_id.user_id", "auto_distribution_id.account_id.user_id"])]
+pub struct FinancialGoalAutoDistribution {
+    pub financial_goal_id: RecordId,
+    pub auto_distribution_id: RecordId,
+}
 
-    InvestmentAutoDistribution|InvestmentAutoDistributionError("investment_auto_distributions" ["investment_id.user_id", "auto_distribution_id.account_id.user_id"]) {
-        investment_id: RecordId,
-        auto_distribution_id: RecordId,
-    }
+#[derive(Debug, Serialize, Deserialize, Clone, ApiEntity)]
+#[api(table = "stable_income_auto_distributions", owner_paths = ["stable_income_id.user_id", "auto_distribution_id.account_id.user_id"])]
+pub struct StableIncomeAutoDistribution {
+    pub stable_income_id: RecordId,
+    pub auto_distribution_id: RecordId,
+}
 
-    TransferAutoDistribution|TransferAutoDistributionError("transfer_auto_distributions" ["auto_distribution_id.account_id.user_id", "metadata_id.user_id"]) {
-        metadata_id: RecordId,
-        account_to: String,
-        auto_distribution_id: RecordId,
-    }
+#[derive(Debug, Serialize, Deserialize, Clone, ApiEntity)]
+#[api(table = "loan_auto_distributions", owner_paths = ["loan_id.user_id", "auto_distribution_id.account_id.user_id"])]
+pub struct LoanAutoDistribution {
+    pub loan_id: RecordId,
+    pub auto_distribution_id: RecordId,
+}
 
-    TransactionAutoDistribution|TransactionAutoDistributionError("transaction_auto_distributions" ["auto_distribution_id.account_id.user_id"]) {
-        auto_distribution_id: RecordId,
-    }
+#[derive(Debug, Serialize, Deserialize, Clone, ApiEntity)]
+#[api(table = "investment_auto_distributions", owner_paths = ["investment_id.user_id", "auto_distribution_id.account_id.user_id"])]
+pub struct InvestmentAutoDistribution {
+    pub investment_id: RecordId,
+    pub auto_distribution_id: RecordId,
+}
 
-    FinancialGoalAllocations|FinancialGoalAllocationsError("financial_goal_allocations") {
-        financial_goal_id: RecordId,
-        account_id: Option<String>,
-        date: DateTime<Utc>,
-        amount: i64 [gt_zero],
-        metadata_id: RecordId,
-    }
-);
+#[derive(Debug, Serialize, Deserialize, Clone, ApiEntity)]
+#[api(table = "transfer_auto_distributions", owner_paths = ["auto_distribution_id.account_id.user_id", "metadata_id.user_id"])]
+pub struct TransferAutoDistribution {
+    pub metadata_id: RecordId,
+    pub account_to: String,
+    pub auto_distribution_id: RecordId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ApiEntity)]
+#[api(table = "transaction_auto_distributions", owner_paths = ["auto_distribution_id.account_id.user_id"])]
+pub struct TransactionAutoDistribution {
+    pub auto_distribution_id: RecordId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ApiEntity)]
+#[api(table = "financial_goal_allocations")]
+pub struct FinancialGoalAllocations {
+    pub financial_goal_id: RecordId,
+    pub account_id: Option<String>,
+    pub date: DateTime<Utc>,
+    #[validate(gt_zero)]
+    pub amount: i64,
+    pub metadata_id: RecordId,
+}
+
+/// The entities served by the API.
+pub static ENTITIES: LazyLock<EntityRegistry> = LazyLock::new(|| {
+    EntityRegistry::new()
+        .register::<User>()
+        .register::<Account>()
+        .register::<Register>()
+        .register::<Creds>()
+        .register::<Tag>()
+        .register::<MetadataTag>()
+        .register::<TagGroup>()
+        .register::<TagGroupTag>()
+        .register::<MetadataTagGroup>()
+        .register::<FinancialGoal>()
+        .register::<Transaction>()
+        .register::<Transfer>()
+        .register::<StableIncome>()
+        .register::<StableIncomeIncome>()
+        .register::<Loan>()
+        .register::<LoanPayment>()
+        .register::<Investment>()
+        .register::<InvestmentReturn>()
+        .register::<Metadata>()
+        .register::<Preference>()
+        .register::<AutoDistribution>()
+        .register::<FinancialGoalAutoDistribution>()
+        .register::<StableIncomeAutoDistribution>()
+        .register::<LoanAutoDistribution>()
+        .register::<InvestmentAutoDistribution>()
+        .register::<TransferAutoDistribution>()
+        .register::<TransactionAutoDistribution>()
+        .register::<FinancialGoalAllocations>()
+});
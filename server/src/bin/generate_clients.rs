@@ -0,0 +1,40 @@
+//! Writes the API clients of `web_client` and of the static pages from the entities.
+//!
+//! Usage: `cargo run -p server --bin generate_clients [RUST_OUTPUT] [TYPESCRIPT_OUTPUT]`,
+//! paths are relative to the `server` directory by default.
+
+#[path = "../api_datatypes.rs"]
+#[allow(dead_code)]
+mod api_datatypes;
+
+use actix_surreal_starter::client_gen::{rust_client, typescript_client, AuthEntities};
+use std::path::PathBuf;
+
+const DEFAULT_RUST_OUTPUT: &str = "../web_client/src/api.rs";
+const DEFAULT_TYPESCRIPT_OUTPUT: &str = "../ssg/static/static/js/api.ts";
+
+fn main() -> std::io::Result<()> {
+    let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let mut args = std::env::args().skip(1);
+    let rust_output = args
+        .next()
+        .map(PathBuf::from)
+        .unwrap_or_else(|| manifest_dir.join(DEFAULT_RUST_OUTPUT));
+    let typescript_output = args
+        .next()
+        .map(PathBuf::from)
+        .unwrap_or_else(|| manifest_dir.join(DEFAULT_TYPESCRIPT_OUTPUT));
+    let entities = api_datatypes::ENTITIES.openapi();
+    let auth = AuthEntities {
+        creds: "Creds",
+        register: "Register",
+    };
+    std::fs::write(&rust_output, rust_client(&entities, &auth))?;
+    std::fs::write(&typescript_output, typescript_client(&entities, &auth))?;
+    println!(
+        "Wrote {} and {}",
+        rust_output.display(),
+        typescript_output.display()
+    );
+    Ok(())
+}
@@ -1,9 +1,13 @@
 mod api_datatypes;
+mod migrations;
 
-use crate::api_datatypes::{configure_endpoints, Creds, Register, RegisterError};
+use crate::api_datatypes::{Creds, Register, RegisterError, ENTITIES};
+use crate::migrations::MIGRATIONS;
+use actix_surreal_starter::openapi::{OpenApiConfig, SwaggerUi};
+use actix_surreal_starter::schema::SchemaConfig;
 use actix_surreal_starter::{
-    build_register_config, ActixSurrealStarter, DbAccessConfig, LoginData, NamesConfig,
-    RegisterConfig, ServerStarter, Users,
+    build_register_config, normalize_email, ActixSurrealStarter, DbAccessConfig, LoginData,
+    NamesConfig, PasswordPolicy, RegisterConfig, ServerStarter, Users,
 };
 use actix_web::web::Json;
 use actix_web::{get, post, web, HttpRequest, HttpResponse, Responder};
@@ -22,6 +26,7 @@ async fn increment(data: Json<i32>) -> impl Responder {
 
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {
+    PasswordPolicy::basic().install("users");
     ActixSurrealStarter::<Creds>::start(
         NamesConfig {
             db_access_config: DbAccessConfig {
@@ -42,8 +47,20 @@ async fn main() -> std::io::Result<()> {
                 "registration_date": surrealdb::Datetime::from(chrono::Utc::now()),
                 "selected_preference": None::<String>,
             }
-            validator: creds.validate()
+            validator(context): creds.validate_async(context).await
         }),
+        ENTITIES.query_builders(),
+        SchemaConfig {
+            entities: ENTITIES.schema(),
+            migrations: MIGRATIONS,
+        },
+        OpenApiConfig {
+            title: "Manger Dog",
+            entities: ENTITIES.openapi(),
+            // Served only by debug builds, from the `swagger-ui` directory of the server.
+            swagger_ui: cfg!(debug_assertions).then(SwaggerUi::default),
+            ..Default::default()
+        },
         |cfg| {
             cfg.service(hello_world).service(increment).route(
                 "/another_hello_world/",
@@ -54,7 +71,7 @@ async fn main() -> std::io::Result<()> {
                     )
                 }),
             );
-            configure_endpoints(cfg);
+            ENTITIES.configure_endpoints(cfg);
             cfg
         },
     )
@@ -73,6 +90,12 @@ impl LoginData for Register {
     fn get_login(&self) -> &String {
         &self.email
     }
+
+    fn normalize_login(&mut self) {
+        if let Some(email) = normalize_email(&self.email) {
+            self.email = email;
+        }
+    }
 }
 
 impl LoginData for Creds {
@@ -87,6 +110,10 @@ impl LoginData for Creds {
     fn get_login(&self) -> &String {
         &self.email
     }
-}
 
-// TODO: Come up with the solution for validating unsigned integers for sign and integers for size
+    fn normalize_login(&mut self) {
+        if let Some(email) = normalize_email(&self.email) {
+            self.email = email;
+        }
+    }
+}
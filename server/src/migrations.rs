@@ -0,0 +1,34 @@
+use actix_surreal_starter::schema::Migration;
+
+pub const MIGRATIONS: &[Migration] = &[Migration {
+    version: 1,
+    name: "update_sessions_table",
+    // The sessions table of schema.surql lacks the token fields written on login and requires
+    // its own `session_id` and `expiration_date`, so the generated schema can't insert into it.
+    // Only the records without tokens, which no login can use, are removed.
+    query: "DEFINE TABLE IF NOT EXISTS sessions SCHEMAFULL; \
+        REMOVE INDEX IF EXISTS session_id_user_id_idx ON TABLE sessions; \
+        REMOVE INDEX IF EXISTS session_id_idx ON TABLE sessions; \
+        REMOVE INDEX IF EXISTS expiration_date_idx ON TABLE sessions; \
+        REMOVE FIELD IF EXISTS session_id ON TABLE sessions; \
+        REMOVE FIELD IF EXISTS expiration_date ON TABLE sessions; \
+        DEFINE FIELD OVERWRITE access_token ON TABLE sessions TYPE string; \
+        DEFINE FIELD OVERWRITE refresh_token ON TABLE sessions TYPE string; \
+        DEFINE FIELD OVERWRITE access_expiration ON TABLE sessions TYPE string; \
+        DEFINE FIELD OVERWRITE refresh_expiration ON TABLE sessions TYPE string; \
+        DEFINE FIELD OVERWRITE user_id ON TABLE sessions TYPE record<users>; \
+        DELETE sessions WHERE access_token IS NONE OR refresh_token IS NONE",
+}, Migration {
+    version: 2,
+    name: "normalize_user_emails",
+    // Logins are looked up with their domain normalized, which for the ASCII domains accepted so
+    // far means lowercase. Emails whose normalized form another user already has are left as
+    // they are, the login falls back to the address as typed to find them.
+    query: "FOR $user IN (SELECT id, email FROM users WHERE array::len(string::split(email, '@')) = 2) { \
+            LET $parts = string::split($user.email, '@'); \
+            LET $normalized = string::concat($parts[0], '@', string::lowercase($parts[1])); \
+            IF $normalized != $user.email AND (SELECT VALUE id FROM users WHERE email = $normalized) = [] { \
+                UPDATE $user.id SET email = $normalized; \
+            }; \
+        }",
+}];
@@ -0,0 +1,949 @@
+// Client of the API, generated from the entities of the server by
+// `cargo run -p server --bin generate_clients`. Regenerate it instead of editing it.
+
+export interface RecordId {
+    tb: string;
+    /** The key by kind, e.g. `{"String": "abc"}`. */
+    id: Record<string, unknown>;
+}
+
+/** A validation error of the server, e.g. `{"code": "StringTooShort", "params": {"min": 8}}`. */
+export interface ValidationError {
+    code: string;
+    params?: Record<string, unknown>;
+}
+
+export type ClientError = "NoAccessToken" | "NoRefreshToken" | "InvalidCredentials" | "EmailTaken" | "InvalidAccessToken";
+
+/** The reason a single item of a batch was not written. */
+export type BatchItemError<E> = "Unauthorized" | "RolledBack" | { Invalid: E };
+
+export type BatchResult<T, E> = ({ Ok: T } | { Err: BatchItemError<E> })[];
+
+export interface HistoryEntry {
+    record: RecordId;
+    action: "insert" | "update" | "delete" | "restore";
+    before: Record<string, unknown> | null;
+    after: Record<string, unknown> | null;
+    user_id: RecordId;
+    timestamp: string;
+}
+
+/** An unsuccessful response. */
+export class ApiError extends Error {
+    constructor(public status: number, public body: string) {
+        super(`${status}: ${body}`);
+    }
+}
+
+async function send(method: string, address: string, body?: unknown): Promise<Response> {
+    const response = await fetch(address, {
+        method,
+        credentials: "include",
+        headers: body === undefined ? {} : { "Content-Type": "application/json" },
+        body: body === undefined ? undefined : JSON.stringify(body),
+    });
+    if (!response.ok) {
+        throw new ApiError(response.status, await response.text());
+    }
+    return response;
+}
+
+/** Successful authentication calls respond with an empty body, failed ones with `{"Err": ...}`. */
+async function sessionResult<E>(response: Response): Promise<E | undefined> {
+    const body = await response.text();
+    return body === "" ? undefined : JSON.parse(body)["Err"];
+}
+
+export interface User {
+    email: string;
+    username: string;
+    password: string;
+    registration_date?: string;
+    selected_preference?: string | null;
+}
+
+export interface UserError {
+    email: ValidationError[];
+    username: ValidationError[];
+    password: ValidationError[];
+    registration_date: ValidationError[];
+    selected_preference: ValidationError[];
+    _entity: ValidationError[];
+}
+
+export interface Account {
+    title: string;
+    user_id?: RecordId;
+    currency_id: RecordId;
+    balance?: number;
+}
+
+export interface AccountError {
+    title: ValidationError[];
+    user_id: ValidationError[];
+    currency_id: ValidationError[];
+    balance: ValidationError[];
+    _entity: ValidationError[];
+}
+
+export interface Register {
+    username: string;
+    email: string;
+    password: string;
+}
+
+export interface RegisterError {
+    username: ValidationError[];
+    email: ValidationError[];
+    password: ValidationError[];
+    _entity: ValidationError[];
+}
+
+export interface Creds {
+    email: string;
+    password: string;
+}
+
+export interface CredsError {
+    email: ValidationError[];
+    password: ValidationError[];
+    _entity: ValidationError[];
+}
+
+export interface Tag {
+    user_id?: RecordId;
+    metadata_id: RecordId;
+}
+
+export interface TagError {
+    user_id: ValidationError[];
+    metadata_id: ValidationError[];
+    _entity: ValidationError[];
+}
+
+export interface MetadataTag {
+    metadata_id: RecordId;
+    tag_id: RecordId;
+    exception: boolean;
+}
+
+export interface MetadataTagError {
+    metadata_id: ValidationError[];
+    tag_id: ValidationError[];
+    exception: ValidationError[];
+    _entity: ValidationError[];
+}
+
+export interface TagGroup {
+    user_id?: RecordId;
+    metadata_id?: RecordId | null;
+}
+
+export interface TagGroupError {
+    user_id: ValidationError[];
+    metadata_id: ValidationError[];
+    _entity: ValidationError[];
+}
+
+export interface TagGroupTag {
+    tag_group_id: RecordId;
+    tag_id: RecordId;
+}
+
+export interface TagGroupTagError {
+    tag_group_id: ValidationError[];
+    tag_id: ValidationError[];
+    _entity: ValidationError[];
+}
+
+export interface MetadataTagGroup {
+    metadata_id: RecordId;
+    tag_group_id: RecordId;
+}
+
+export interface MetadataTagGroupError {
+    metadata_id: ValidationError[];
+    tag_group_id: ValidationError[];
+    _entity: ValidationError[];
+}
+
+export interface FinancialGoal {
+    user_id?: RecordId;
+    currency_id: RecordId;
+    start_date: string;
+    end_date: string;
+    target_income: number;
+    metadata_id: RecordId;
+    version?: number;
+}
+
+export interface FinancialGoalError {
+    user_id: ValidationError[];
+    currency_id: ValidationError[];
+    start_date: ValidationError[];
+    end_date: ValidationError[];
+    target_income: ValidationError[];
+    metadata_id: ValidationError[];
+    _entity: ValidationError[];
+}
+
+export interface Transaction {
+    account_id: RecordId;
+    amount: number;
+    date: string;
+    metadata_id: RecordId;
+}
+
+export interface TransactionError {
+    account_id: ValidationError[];
+    amount: ValidationError[];
+    date: ValidationError[];
+    metadata_id: ValidationError[];
+    _entity: ValidationError[];
+}
+
+export interface Transfer {
+    account_from: RecordId;
+    account_to: RecordId;
+    amount_from: number;
+    amount_to: number;
+    conversion_rate: number;
+    fee: number;
+    metadata_id: RecordId;
+}
+
+export interface TransferError {
+    account_from: ValidationError[];
+    account_to: ValidationError[];
+    amount_from: ValidationError[];
+    amount_to: ValidationError[];
+    conversion_rate: ValidationError[];
+    fee: ValidationError[];
+    metadata_id: ValidationError[];
+    _entity: ValidationError[];
+}
+
+export interface StableIncome {
+    user_id?: RecordId;
+    currency_id: RecordId;
+    amount_per_month: number;
+    start_date: string;
+    end_date?: string | null;
+    last_update_date?: string;
+    metadata_id: RecordId;
+}
+
+export interface StableIncomeError {
+    user_id: ValidationError[];
+    currency_id: ValidationError[];
+    amount_per_month: ValidationError[];
+    start_date: ValidationError[];
+    end_date: ValidationError[];
+    last_update_date: ValidationError[];
+    metadata_id: ValidationError[];
+    _entity: ValidationError[];
+}
+
+export interface StableIncomeIncome {
+    stable_income_id: RecordId;
+    transaction_id: RecordId;
+}
+
+export interface StableIncomeIncomeError {
+    stable_income_id: ValidationError[];
+    transaction_id: ValidationError[];
+    _entity: ValidationError[];
+}
+
+export interface Loan {
+    user_id?: RecordId;
+    currency_id: RecordId;
+    principal_amount: number;
+    interest_rate: number;
+    start_date: string;
+    end_date?: string | null;
+    interest_rate_type: string;
+    compounding_frequency: string;
+    metadata_id: RecordId;
+}
+
+export interface LoanError {
+    user_id: ValidationError[];
+    currency_id: ValidationError[];
+    principal_amount: ValidationError[];
+    interest_rate: ValidationError[];
+    start_date: ValidationError[];
+    end_date: ValidationError[];
+    interest_rate_type: ValidationError[];
+    compounding_frequency: ValidationError[];
+    metadata_id: ValidationError[];
+    _entity: ValidationError[];
+}
+
+export interface LoanPayment {
+    loan_id: RecordId;
+    transaction_id: RecordId;
+}
+
+export interface LoanPaymentError {
+    loan_id: ValidationError[];
+    transaction_id: ValidationError[];
+    _entity: ValidationError[];
+}
+
+export interface Investment {
+    user_id?: RecordId;
+    currency_id: RecordId;
+    type: string;
+    compounding_frequency: string;
+    principal_amount: number;
+    start_date: string;
+    expected_end_date?: string | null;
+    end_date?: string | null;
+    risk_level: string;
+    expected_return: number;
+    metadata_id: RecordId;
+}
+
+export interface InvestmentError {
+    user_id: ValidationError[];
+    currency_id: ValidationError[];
+    type: ValidationError[];
+    compounding_frequency: ValidationError[];
+    principal_amount: ValidationError[];
+    start_date: ValidationError[];
+    expected_end_date: ValidationError[];
+    end_date: ValidationError[];
+    risk_level: ValidationError[];
+    expected_return: ValidationError[];
+    metadata_id: ValidationError[];
+    _entity: ValidationError[];
+}
+
+export interface InvestmentReturn {
+    investment_id: RecordId;
+    transaction_id: RecordId;
+}
+
+export interface InvestmentReturnError {
+    investment_id: ValidationError[];
+    transaction_id: ValidationError[];
+    _entity: ValidationError[];
+}
+
+export interface Metadata {
+    title?: string | null;
+    description?: string | null;
+    user_id?: RecordId;
+}
+
+export interface MetadataError {
+    title: ValidationError[];
+    description: ValidationError[];
+    user_id: ValidationError[];
+    _entity: ValidationError[];
+}
+
+export interface Preference {
+    user_id?: RecordId;
+    default_currency_id: RecordId;
+    language: string;
+}
+
+export interface PreferenceError {
+    user_id: ValidationError[];
+    default_currency_id: ValidationError[];
+    language: ValidationError[];
+    _entity: ValidationError[];
+}
+
+export interface AutoDistribution {
+    ratio: number;
+    account_id: RecordId;
+    metadata_id: RecordId;
+    record_metadata_id: RecordId;
+}
+
+export interface AutoDistributionError {
+    ratio: ValidationError[];
+    account_id: ValidationError[];
+    metadata_id: ValidationError[];
+    record_metadata_id: ValidationError[];
+    _entity: ValidationError[];
+}
+
+export interface FinancialGoalAutoDistribution {
+    financial_goal_id: RecordId;
+    auto_distribution_id: RecordId;
+}
+
+export interface FinancialGoalAutoDistributionError {
+    financial_goal_id: ValidationError[];
+    auto_distribution_id: ValidationError[];
+    _entity: ValidationError[];
+}
+
+export interface StableIncomeAutoDistribution {
+    stable_income_id: RecordId;
+    auto_distribution_id: RecordId;
+}
+
+export interface StableIncomeAutoDistributionError {
+    stable_income_id: ValidationError[];
+    auto_distribution_id: ValidationError[];
+    _entity: ValidationError[];
+}
+
+export interface LoanAutoDistribution {
+    loan_id: RecordId;
+    auto_distribution_id: RecordId;
+}
+
+export interface LoanAutoDistributionError {
+    loan_id: ValidationError[];
+    auto_distribution_id: ValidationError[];
+    _entity: ValidationError[];
+}
+
+export interface InvestmentAutoDistribution {
+    investment_id: RecordId;
+    auto_distribution_id: RecordId;
+}
+
+export interface InvestmentAutoDistributionError {
+    investment_id: ValidationError[];
+    auto_distribution_id: ValidationError[];
+    _entity: ValidationError[];
+}
+
+export interface TransferAutoDistribution {
+    metadata_id: RecordId;
+    account_to: string;
+    auto_distribution_id: RecordId;
+}
+
+export interface TransferAutoDistributionError {
+    metadata_id: ValidationError[];
+    account_to: ValidationError[];
+    auto_distribution_id: ValidationError[];
+    _entity: ValidationError[];
+}
+
+export interface TransactionAutoDistribution {
+    auto_distribution_id: RecordId;
+}
+
+export interface TransactionAutoDistributionError {
+    auto_distribution_id: ValidationError[];
+    _entity: ValidationError[];
+}
+
+export interface FinancialGoalAllocations {
+    financial_goal_id: RecordId;
+    account_id?: string | null;
+    date: string;
+    amount: number;
+    metadata_id: RecordId;
+}
+
+export interface FinancialGoalAllocationsError {
+    financial_goal_id: ValidationError[];
+    account_id: ValidationError[];
+    date: ValidationError[];
+    amount: ValidationError[];
+    metadata_id: ValidationError[];
+    _entity: ValidationError[];
+}
+
+/** Routes of the `accounts` table. */
+export const accounts = {
+    address: "/api/accounts",
+    all: async (): Promise<Account[]> => (await send("GET", "/api/accounts/all")).json(),
+    insert: async (value: Account): Promise<RecordId> => (await send("POST", "/api/accounts", value)).json(),
+    update: async (id: RecordId, data: Account): Promise<void> => {
+        await send("PUT", "/api/accounts", { id, data });
+    },
+    delete: async (id: RecordId): Promise<void> => {
+        await send("DELETE", "/api/accounts", id);
+    },
+    insertBatch: async (values: Account[]): Promise<BatchResult<RecordId, AccountError>> =>
+        (await send("POST", "/api/accounts/batch", values)).json(),
+    updateBatch: async (values: { id: RecordId; data: Account }[]): Promise<BatchResult<null, AccountError>> =>
+        (await send("PUT", "/api/accounts/batch", values)).json(),
+    deleteBatch: async (ids: RecordId[]): Promise<BatchResult<null, null>> =>
+        (await send("DELETE", "/api/accounts/batch", ids)).json(),
+    restore: async (id: RecordId): Promise<void> => {
+        await send("POST", "/api/accounts/restore", id);
+    },
+    trash: async (): Promise<Account[]> => (await send("GET", "/api/accounts/trash")).json(),
+    /** The changes of the record, oldest first. */
+    history: async (id: string): Promise<HistoryEntry[]> =>
+        (await send("GET", `/api/accounts/history?id=${encodeURIComponent(id)}`)).json(),
+};
+
+/** Routes of the `tags` table. */
+export const tags = {
+    address: "/api/tags",
+    all: async (): Promise<Tag[]> => (await send("GET", "/api/tags/all")).json(),
+    insert: async (value: Tag): Promise<RecordId> => (await send("POST", "/api/tags", value)).json(),
+    update: async (id: RecordId, data: Tag): Promise<void> => {
+        await send("PUT", "/api/tags", { id, data });
+    },
+    delete: async (id: RecordId): Promise<void> => {
+        await send("DELETE", "/api/tags", id);
+    },
+    insertBatch: async (values: Tag[]): Promise<BatchResult<RecordId, TagError>> =>
+        (await send("POST", "/api/tags/batch", values)).json(),
+    updateBatch: async (values: { id: RecordId; data: Tag }[]): Promise<BatchResult<null, TagError>> =>
+        (await send("PUT", "/api/tags/batch", values)).json(),
+    deleteBatch: async (ids: RecordId[]): Promise<BatchResult<null, null>> =>
+        (await send("DELETE", "/api/tags/batch", ids)).json(),
+};
+
+/** Routes of the `metadata_tags` table. */
+export const metadata_tags = {
+    address: "/api/metadata_tags",
+    all: async (): Promise<MetadataTag[]> => (await send("GET", "/api/metadata_tags/all")).json(),
+    insert: async (value: MetadataTag): Promise<RecordId> => (await send("POST", "/api/metadata_tags", value)).json(),
+    update: async (id: RecordId, data: MetadataTag): Promise<void> => {
+        await send("PUT", "/api/metadata_tags", { id, data });
+    },
+    delete: async (id: RecordId): Promise<void> => {
+        await send("DELETE", "/api/metadata_tags", id);
+    },
+    insertBatch: async (values: MetadataTag[]): Promise<BatchResult<RecordId, MetadataTagError>> =>
+        (await send("POST", "/api/metadata_tags/batch", values)).json(),
+    updateBatch: async (values: { id: RecordId; data: MetadataTag }[]): Promise<BatchResult<null, MetadataTagError>> =>
+        (await send("PUT", "/api/metadata_tags/batch", values)).json(),
+    deleteBatch: async (ids: RecordId[]): Promise<BatchResult<null, null>> =>
+        (await send("DELETE", "/api/metadata_tags/batch", ids)).json(),
+};
+
+/** Routes of the `tag_groups` table. */
+export const tag_groups = {
+    address: "/api/tag_groups",
+    all: async (): Promise<TagGroup[]> => (await send("GET", "/api/tag_groups/all")).json(),
+    insert: async (value: TagGroup): Promise<RecordId> => (await send("POST", "/api/tag_groups", value)).json(),
+    update: async (id: RecordId, data: TagGroup): Promise<void> => {
+        await send("PUT", "/api/tag_groups", { id, data });
+    },
+    delete: async (id: RecordId): Promise<void> => {
+        await send("DELETE", "/api/tag_groups", id);
+    },
+    insertBatch: async (values: TagGroup[]): Promise<BatchResult<RecordId, TagGroupError>> =>
+        (await send("POST", "/api/tag_groups/batch", values)).json(),
+    updateBatch: async (values: { id: RecordId; data: TagGroup }[]): Promise<BatchResult<null, TagGroupError>> =>
+        (await send("PUT", "/api/tag_groups/batch", values)).json(),
+    deleteBatch: async (ids: RecordId[]): Promise<BatchResult<null, null>> =>
+        (await send("DELETE", "/api/tag_groups/batch", ids)).json(),
+};
+
+/** Routes of the `tag_group_tags` table. */
+export const tag_group_tags = {
+    address: "/api/tag_group_tags",
+    all: async (): Promise<TagGroupTag[]> => (await send("GET", "/api/tag_group_tags/all")).json(),
+    insert: async (value: TagGroupTag): Promise<RecordId> => (await send("POST", "/api/tag_group_tags", value)).json(),
+    update: async (id: RecordId, data: TagGroupTag): Promise<void> => {
+        await send("PUT", "/api/tag_group_tags", { id, data });
+    },
+    delete: async (id: RecordId): Promise<void> => {
+        await send("DELETE", "/api/tag_group_tags", id);
+    },
+    insertBatch: async (values: TagGroupTag[]): Promise<BatchResult<RecordId, TagGroupTagError>> =>
+        (await send("POST", "/api/tag_group_tags/batch", values)).json(),
+    updateBatch: async (values: { id: RecordId; data: TagGroupTag }[]): Promise<BatchResult<null, TagGroupTagError>> =>
+        (await send("PUT", "/api/tag_group_tags/batch", values)).json(),
+    deleteBatch: async (ids: RecordId[]): Promise<BatchResult<null, null>> =>
+        (await send("DELETE", "/api/tag_group_tags/batch", ids)).json(),
+};
+
+/** Routes of the `metadata_tag_groups` table. */
+export const metadata_tag_groups = {
+    address: "/api/metadata_tag_groups",
+    all: async (): Promise<MetadataTagGroup[]> => (await send("GET", "/api/metadata_tag_groups/all")).json(),
+    insert: async (value: MetadataTagGroup): Promise<RecordId> => (await send("POST", "/api/metadata_tag_groups", value)).json(),
+    update: async (id: RecordId, data: MetadataTagGroup): Promise<void> => {
+        await send("PUT", "/api/metadata_tag_groups", { id, data });
+    },
+    delete: async (id: RecordId): Promise<void> => {
+        await send("DELETE", "/api/metadata_tag_groups", id);
+    },
+    insertBatch: async (values: MetadataTagGroup[]): Promise<BatchResult<RecordId, MetadataTagGroupError>> =>
+        (await send("POST", "/api/metadata_tag_groups/batch", values)).json(),
+    updateBatch: async (values: { id: RecordId; data: MetadataTagGroup }[]): Promise<BatchResult<null, MetadataTagGroupError>> =>
+        (await send("PUT", "/api/metadata_tag_groups/batch", values)).json(),
+    deleteBatch: async (ids: RecordId[]): Promise<BatchResult<null, null>> =>
+        (await send("DELETE", "/api/metadata_tag_groups/batch", ids)).json(),
+};
+
+/** Routes of the `financial_goals` table. */
+export const financial_goals = {
+    address: "/api/financial_goals",
+    all: async (): Promise<FinancialGoal[]> => (await send("GET", "/api/financial_goals/all")).json(),
+    insert: async (value: FinancialGoal): Promise<RecordId> => (await send("POST", "/api/financial_goals", value)).json(),
+    update: async (id: RecordId, data: FinancialGoal): Promise<void> => {
+        await send("PUT", "/api/financial_goals", { id, data });
+    },
+    delete: async (id: RecordId): Promise<void> => {
+        await send("DELETE", "/api/financial_goals", id);
+    },
+    insertBatch: async (values: FinancialGoal[]): Promise<BatchResult<RecordId, FinancialGoalError>> =>
+        (await send("POST", "/api/financial_goals/batch", values)).json(),
+    updateBatch: async (values: { id: RecordId; data: FinancialGoal }[]): Promise<BatchResult<null, FinancialGoalError>> =>
+        (await send("PUT", "/api/financial_goals/batch", values)).json(),
+    deleteBatch: async (ids: RecordId[]): Promise<BatchResult<null, null>> =>
+        (await send("DELETE", "/api/financial_goals/batch", ids)).json(),
+};
+
+/** Routes of the `transactions` table. */
+export const transactions = {
+    address: "/api/transactions",
+    all: async (): Promise<Transaction[]> => (await send("GET", "/api/transactions/all")).json(),
+    insert: async (value: Transaction): Promise<RecordId> => (await send("POST", "/api/transactions", value)).json(),
+    update: async (id: RecordId, data: Transaction): Promise<void> => {
+        await send("PUT", "/api/transactions", { id, data });
+    },
+    delete: async (id: RecordId): Promise<void> => {
+        await send("DELETE", "/api/transactions", id);
+    },
+    insertBatch: async (values: Transaction[]): Promise<BatchResult<RecordId, TransactionError>> =>
+        (await send("POST", "/api/transactions/batch", values)).json(),
+    updateBatch: async (values: { id: RecordId; data: Transaction }[]): Promise<BatchResult<null, TransactionError>> =>
+        (await send("PUT", "/api/transactions/batch", values)).json(),
+    deleteBatch: async (ids: RecordId[]): Promise<BatchResult<null, null>> =>
+        (await send("DELETE", "/api/transactions/batch", ids)).json(),
+    /** The changes of the record, oldest first. */
+    history: async (id: string): Promise<HistoryEntry[]> =>
+        (await send("GET", `/api/transactions/history?id=${encodeURIComponent(id)}`)).json(),
+    monthly_amount_by_account: async (): Promise<Record<string, unknown>[]> => (await send("GET", "/api/transactions/aggregate/monthly_amount_by_account")).json(),
+};
+
+/** Routes of the `transfers` table. */
+export const transfers = {
+    address: "/api/transfers",
+    all: async (): Promise<Transfer[]> => (await send("GET", "/api/transfers/all")).json(),
+    insert: async (value: Transfer): Promise<RecordId> => (await send("POST", "/api/transfers", value)).json(),
+    update: async (id: RecordId, data: Transfer): Promise<void> => {
+        await send("PUT", "/api/transfers", { id, data });
+    },
+    delete: async (id: RecordId): Promise<void> => {
+        await send("DELETE", "/api/transfers", id);
+    },
+    insertBatch: async (values: Transfer[]): Promise<BatchResult<RecordId, TransferError>> =>
+        (await send("POST", "/api/transfers/batch", values)).json(),
+    updateBatch: async (values: { id: RecordId; data: Transfer }[]): Promise<BatchResult<null, TransferError>> =>
+        (await send("PUT", "/api/transfers/batch", values)).json(),
+    deleteBatch: async (ids: RecordId[]): Promise<BatchResult<null, null>> =>
+        (await send("DELETE", "/api/transfers/batch", ids)).json(),
+    /** The changes of the record, oldest first. */
+    history: async (id: string): Promise<HistoryEntry[]> =>
+        (await send("GET", `/api/transfers/history?id=${encodeURIComponent(id)}`)).json(),
+};
+
+/** Routes of the `stable_incomes` table. */
+export const stable_incomes = {
+    address: "/api/stable_incomes",
+    all: async (): Promise<StableIncome[]> => (await send("GET", "/api/stable_incomes/all")).json(),
+    insert: async (value: StableIncome): Promise<RecordId> => (await send("POST", "/api/stable_incomes", value)).json(),
+    update: async (id: RecordId, data: StableIncome): Promise<void> => {
+        await send("PUT", "/api/stable_incomes", { id, data });
+    },
+    delete: async (id: RecordId): Promise<void> => {
+        await send("DELETE", "/api/stable_incomes", id);
+    },
+    insertBatch: async (values: StableIncome[]): Promise<BatchResult<RecordId, StableIncomeError>> =>
+        (await send("POST", "/api/stable_incomes/batch", values)).json(),
+    updateBatch: async (values: { id: RecordId; data: StableIncome }[]): Promise<BatchResult<null, StableIncomeError>> =>
+        (await send("PUT", "/api/stable_incomes/batch", values)).json(),
+    deleteBatch: async (ids: RecordId[]): Promise<BatchResult<null, null>> =>
+        (await send("DELETE", "/api/stable_incomes/batch", ids)).json(),
+};
+
+/** Routes of the `stable_income_incomes` table. */
+export const stable_income_incomes = {
+    address: "/api/stable_income_incomes",
+    all: async (): Promise<StableIncomeIncome[]> => (await send("GET", "/api/stable_income_incomes/all")).json(),
+    insert: async (value: StableIncomeIncome): Promise<RecordId> => (await send("POST", "/api/stable_income_incomes", value)).json(),
+    update: async (id: RecordId, data: StableIncomeIncome): Promise<void> => {
+        await send("PUT", "/api/stable_income_incomes", { id, data });
+    },
+    delete: async (id: RecordId): Promise<void> => {
+        await send("DELETE", "/api/stable_income_incomes", id);
+    },
+    insertBatch: async (values: StableIncomeIncome[]): Promise<BatchResult<RecordId, StableIncomeIncomeError>> =>
+        (await send("POST", "/api/stable_income_incomes/batch", values)).json(),
+    updateBatch: async (values: { id: RecordId; data: StableIncomeIncome }[]): Promise<BatchResult<null, StableIncomeIncomeError>> =>
+        (await send("PUT", "/api/stable_income_incomes/batch", values)).json(),
+    deleteBatch: async (ids: RecordId[]): Promise<BatchResult<null, null>> =>
+        (await send("DELETE", "/api/stable_income_incomes/batch", ids)).json(),
+};
+
+/** Routes of the `loans` table. */
+export const loans = {
+    address: "/api/loans",
+    all: async (): Promise<Loan[]> => (await send("GET", "/api/loans/all")).json(),
+    insert: async (value: Loan): Promise<RecordId> => (await send("POST", "/api/loans", value)).json(),
+    update: async (id: RecordId, data: Loan): Promise<void> => {
+        await send("PUT", "/api/loans", { id, data });
+    },
+    delete: async (id: RecordId): Promise<void> => {
+        await send("DELETE", "/api/loans", id);
+    },
+    insertBatch: async (values: Loan[]): Promise<BatchResult<RecordId, LoanError>> =>
+        (await send("POST", "/api/loans/batch", values)).json(),
+    updateBatch: async (values: { id: RecordId; data: Loan }[]): Promise<BatchResult<null, LoanError>> =>
+        (await send("PUT", "/api/loans/batch", values)).json(),
+    deleteBatch: async (ids: RecordId[]): Promise<BatchResult<null, null>> =>
+        (await send("DELETE", "/api/loans/batch", ids)).json(),
+    restore: async (id: RecordId): Promise<void> => {
+        await send("POST", "/api/loans/restore", id);
+    },
+    trash: async (): Promise<Loan[]> => (await send("GET", "/api/loans/trash")).json(),
+    /** The changes of the record, oldest first. */
+    history: async (id: string): Promise<HistoryEntry[]> =>
+        (await send("GET", `/api/loans/history?id=${encodeURIComponent(id)}`)).json(),
+};
+
+/** Routes of the `loan_payments` table. */
+export const loan_payments = {
+    address: "/api/loan_payments",
+    all: async (): Promise<LoanPayment[]> => (await send("GET", "/api/loan_payments/all")).json(),
+    insert: async (value: LoanPayment): Promise<RecordId> => (await send("POST", "/api/loan_payments", value)).json(),
+    update: async (id: RecordId, data: LoanPayment): Promise<void> => {
+        await send("PUT", "/api/loan_payments", { id, data });
+    },
+    delete: async (id: RecordId): Promise<void> => {
+        await send("DELETE", "/api/loan_payments", id);
+    },
+    insertBatch: async (values: LoanPayment[]): Promise<BatchResult<RecordId, LoanPaymentError>> =>
+        (await send("POST", "/api/loan_payments/batch", values)).json(),
+    updateBatch: async (values: { id: RecordId; data: LoanPayment }[]): Promise<BatchResult<null, LoanPaymentError>> =>
+        (await send("PUT", "/api/loan_payments/batch", values)).json(),
+    deleteBatch: async (ids: RecordId[]): Promise<BatchResult<null, null>> =>
+        (await send("DELETE", "/api/loan_payments/batch", ids)).json(),
+};
+
+/** Routes of the `investments` table. */
+export const investments = {
+    address: "/api/investments",
+    all: async (): Promise<Investment[]> => (await send("GET", "/api/investments/all")).json(),
+    insert: async (value: Investment): Promise<RecordId> => (await send("POST", "/api/investments", value)).json(),
+    update: async (id: RecordId, data: Investment): Promise<void> => {
+        await send("PUT", "/api/investments", { id, data });
+    },
+    delete: async (id: RecordId): Promise<void> => {
+        await send("DELETE", "/api/investments", id);
+    },
+    insertBatch: async (values: Investment[]): Promise<BatchResult<RecordId, InvestmentError>> =>
+        (await send("POST", "/api/investments/batch", values)).json(),
+    updateBatch: async (values: { id: RecordId; data: Investment }[]): Promise<BatchResult<null, InvestmentError>> =>
+        (await send("PUT", "/api/investments/batch", values)).json(),
+    deleteBatch: async (ids: RecordId[]): Promise<BatchResult<null, null>> =>
+        (await send("DELETE", "/api/investments/batch", ids)).json(),
+};
+
+/** Routes of the `investment_returns` table. */
+export const investment_returns = {
+    address: "/api/investment_returns",
+    all: async (): Promise<InvestmentReturn[]> => (await send("GET", "/api/investment_returns/all")).json(),
+    insert: async (value: InvestmentReturn): Promise<RecordId> => (await send("POST", "/api/investment_returns", value)).json(),
+    update: async (id: RecordId, data: InvestmentReturn): Promise<void> => {
+        await send("PUT", "/api/investment_returns", { id, data });
+    },
+    delete: async (id: RecordId): Promise<void> => {
+        await send("DELETE", "/api/investment_returns", id);
+    },
+    insertBatch: async (values: InvestmentReturn[]): Promise<BatchResult<RecordId, InvestmentReturnError>> =>
+        (await send("POST", "/api/investment_returns/batch", values)).json(),
+    updateBatch: async (values: { id: RecordId; data: InvestmentReturn }[]): Promise<BatchResult<null, InvestmentReturnError>> =>
+        (await send("PUT", "/api/investment_returns/batch", values)).json(),
+    deleteBatch: async (ids: RecordId[]): Promise<BatchResult<null, null>> =>
+        (await send("DELETE", "/api/investment_returns/batch", ids)).json(),
+};
+
+/** Routes of the `metadata` table. */
+export const metadata = {
+    address: "/api/metadata",
+    all: async (): Promise<Metadata[]> => (await send("GET", "/api/metadata/all")).json(),
+    insert: async (value: Metadata): Promise<RecordId> => (await send("POST", "/api/metadata", value)).json(),
+    update: async (id: RecordId, data: Metadata): Promise<void> => {
+        await send("PUT", "/api/metadata", { id, data });
+    },
+    delete: async (id: RecordId): Promise<void> => {
+        await send("DELETE", "/api/metadata", id);
+    },
+    insertBatch: async (values: Metadata[]): Promise<BatchResult<RecordId, MetadataError>> =>
+        (await send("POST", "/api/metadata/batch", values)).json(),
+    updateBatch: async (values: { id: RecordId; data: Metadata }[]): Promise<BatchResult<null, MetadataError>> =>
+        (await send("PUT", "/api/metadata/batch", values)).json(),
+    deleteBatch: async (ids: RecordId[]): Promise<BatchResult<null, null>> =>
+        (await send("DELETE", "/api/metadata/batch", ids)).json(),
+    /** The matching records with their `score`, best matches first. */
+    search: async (q: string): Promise<(Metadata & { score: number })[]> =>
+        (await send("GET", `/api/metadata/search?q=${encodeURIComponent(q)}`)).json(),
+};
+
+/** Routes of the `preferences` table. */
+export const preferences = {
+    address: "/api/preferences",
+    all: async (): Promise<Preference[]> => (await send("GET", "/api/preferences/all")).json(),
+    insert: async (value: Preference): Promise<RecordId> => (await send("POST", "/api/preferences", value)).json(),
+    update: async (id: RecordId, data: Preference): Promise<void> => {
+        await send("PUT", "/api/preferences", { id, data });
+    },
+    delete: async (id: RecordId): Promise<void> => {
+        await send("DELETE", "/api/preferences", id);
+    },
+    insertBatch: async (values: Preference[]): Promise<BatchResult<RecordId, PreferenceError>> =>
+        (await send("POST", "/api/preferences/batch", values)).json(),
+    updateBatch: async (values: { id: RecordId; data: Preference }[]): Promise<BatchResult<null, PreferenceError>> =>
+        (await send("PUT", "/api/preferences/batch", values)).json(),
+    deleteBatch: async (ids: RecordId[]): Promise<BatchResult<null, null>> =>
+        (await send("DELETE", "/api/preferences/batch", ids)).json(),
+};
+
+/** Routes of the `auto_distributions` table. */
+export const auto_distributions = {
+    address: "/api/auto_distributions",
+    all: async (): Promise<AutoDistribution[]> => (await send("GET", "/api/auto_distributions/all")).json(),
+    insert: async (value: AutoDistribution): Promise<RecordId> => (await send("POST", "/api/auto_distributions", value)).json(),
+    update: async (id: RecordId, data: AutoDistribution): Promise<void> => {
+        await send("PUT", "/api/auto_distributions", { id, data });
+    },
+    delete: async (id: RecordId): Promise<void> => {
+        await send("DELETE", "/api/auto_distributions", id);
+    },
+    insertBatch: async (values: AutoDistribution[]): Promise<BatchResult<RecordId, AutoDistributionError>> =>
+        (await send("POST", "/api/auto_distributions/batch", values)).json(),
+    updateBatch: async (values: { id: RecordId; data: AutoDistribution }[]): Promise<BatchResult<null, AutoDistributionError>> =>
+        (await send("PUT", "/api/auto_distributions/batch", values)).json(),
+    deleteBatch: async (ids: RecordId[]): Promise<BatchResult<null, null>> =>
+        (await send("DELETE", "/api/auto_distributions/batch", ids)).json(),
+};
+
+/** Routes of the `financial_goal_auto_distributions` table. */
+export const financial_goal_auto_distributions = {
+    address: "/api/financial_goal_auto_distributions",
+    all: async (): Promise<FinancialGoalAutoDistribution[]> => (await send("GET", "/api/financial_goal_auto_distributions/all")).json(),
+    insert: async (value: FinancialGoalAutoDistribution): Promise<RecordId> => (await send("POST", "/api/financial_goal_auto_distributions", value)).json(),
+    update: async (id: RecordId, data: FinancialGoalAutoDistribution): Promise<void> => {
+        await send("PUT", "/api/financial_goal_auto_distributions", { id, data });
+    },
+    delete: async (id: RecordId): Promise<void> => {
+        await send("DELETE", "/api/financial_goal_auto_distributions", id);
+    },
+    insertBatch: async (values: FinancialGoalAutoDistribution[]): Promise<BatchResult<RecordId, FinancialGoalAutoDistributionError>> =>
+        (await send("POST", "/api/financial_goal_auto_distributions/batch", values)).json(),
+    updateBatch: async (values: { id: RecordId; data: FinancialGoalAutoDistribution }[]): Promise<BatchResult<null, FinancialGoalAutoDistributionError>> =>
+        (await send("PUT", "/api/financial_goal_auto_distributions/batch", values)).json(),
+    deleteBatch: async (ids: RecordId[]): Promise<BatchResult<null, null>> =>
+        (await send("DELETE", "/api/financial_goal_auto_distributions/batch", ids)).json(),
+};
+
+/** Routes of the `stable_income_auto_distributions` table. */
+export const stable_income_auto_distributions = {
+    address: "/api/stable_income_auto_distributions",
+    all: async (): Promise<StableIncomeAutoDistribution[]> => (await send("GET", "/api/stable_income_auto_distributions/all")).json(),
+    insert: async (value: StableIncomeAutoDistribution): Promise<RecordId> => (await send("POST", "/api/stable_income_auto_distributions", value)).json(),
+    update: async (id: RecordId, data: StableIncomeAutoDistribution): Promise<void> => {
+        await send("PUT", "/api/stable_income_auto_distributions", { id, data });
+    },
+    delete: async (id: RecordId): Promise<void> => {
+        await send("DELETE", "/api/stable_income_auto_distributions", id);
+    },
+    insertBatch: async (values: StableIncomeAutoDistribution[]): Promise<BatchResult<RecordId, StableIncomeAutoDistributionError>> =>
+        (await send("POST", "/api/stable_income_auto_distributions/batch", values)).json(),
+    updateBatch: async (values: { id: RecordId; data: StableIncomeAutoDistribution }[]): Promise<BatchResult<null, StableIncomeAutoDistributionError>> =>
+        (await send("PUT", "/api/stable_income_auto_distributions/batch", values)).json(),
+    deleteBatch: async (ids: RecordId[]): Promise<BatchResult<null, null>> =>
+        (await send("DELETE", "/api/stable_income_auto_distributions/batch", ids)).json(),
+};
+
+/** Routes of the `loan_auto_distributions` table. */
+export const loan_auto_distributions = {
+    address: "/api/loan_auto_distributions",
+    all: async (): Promise<LoanAutoDistribution[]> => (await send("GET", "/api/loan_auto_distributions/all")).json(),
+    insert: async (value: LoanAutoDistribution): Promise<RecordId> => (await send("POST", "/api/loan_auto_distributions", value)).json(),
+    update: async (id: RecordId, data: LoanAutoDistribution): Promise<void> => {
+        await send("PUT", "/api/loan_auto_distributions", { id, data });
+    },
+    delete: async (id: RecordId): Promise<void> => {
+        await send("DELETE", "/api/loan_auto_distributions", id);
+    },
+    insertBatch: async (values: LoanAutoDistribution[]): Promise<BatchResult<RecordId, LoanAutoDistributionError>> =>
+        (await send("POST", "/api/loan_auto_distributions/batch", values)).json(),
+    updateBatch: async (values: { id: RecordId; data: LoanAutoDistribution }[]): Promise<BatchResult<null, LoanAutoDistributionError>> =>
+        (await send("PUT", "/api/loan_auto_distributions/batch", values)).json(),
+    deleteBatch: async (ids: RecordId[]): Promise<BatchResult<null, null>> =>
+        (await send("DELETE", "/api/loan_auto_distributions/batch", ids)).json(),
+};
+
+/** Routes of the `investment_auto_distributions` table. */
+export const investment_auto_distributions = {
+    address: "/api/investment_auto_distributions",
+    all: async (): Promise<InvestmentAutoDistribution[]> => (await send("GET", "/api/investment_auto_distributions/all")).json(),
+    insert: async (value: InvestmentAutoDistribution): Promise<RecordId> => (await send("POST", "/api/investment_auto_distributions", value)).json(),
+    update: async (id: RecordId, data: InvestmentAutoDistribution): Promise<void> => {
+        await send("PUT", "/api/investment_auto_distributions", { id, data });
+    },
+    delete: async (id: RecordId): Promise<void> => {
+        await send("DELETE", "/api/investment_auto_distributions", id);
+    },
+    insertBatch: async (values: InvestmentAutoDistribution[]): Promise<BatchResult<RecordId, InvestmentAutoDistributionError>> =>
+        (await send("POST", "/api/investment_auto_distributions/batch", values)).json(),
+    updateBatch: async (values: { id: RecordId; data: InvestmentAutoDistribution }[]): Promise<BatchResult<null, InvestmentAutoDistributionError>> =>
+        (await send("PUT", "/api/investment_auto_distributions/batch", values)).json(),
+    deleteBatch: async (ids: RecordId[]): Promise<BatchResult<null, null>> =>
+        (await send("DELETE", "/api/investment_auto_distributions/batch", ids)).json(),
+};
+
+/** Routes of the `transfer_auto_distributions` table. */
+export const transfer_auto_distributions = {
+    address: "/api/transfer_auto_distributions",
+    all: async (): Promise<TransferAutoDistribution[]> => (await send("GET", "/api/transfer_auto_distributions/all")).json(),
+    insert: async (value: TransferAutoDistribution): Promise<RecordId> => (await send("POST", "/api/transfer_auto_distributions", value)).json(),
+    update: async (id: RecordId, data: TransferAutoDistribution): Promise<void> => {
+        await send("PUT", "/api/transfer_auto_distributions", { id, data });
+    },
+    delete: async (id: RecordId): Promise<void> => {
+        await send("DELETE", "/api/transfer_auto_distributions", id);
+    },
+    insertBatch: async (values: TransferAutoDistribution[]): Promise<BatchResult<RecordId, TransferAutoDistributionError>> =>
+        (await send("POST", "/api/transfer_auto_distributions/batch", values)).json(),
+    updateBatch: async (values: { id: RecordId; data: TransferAutoDistribution }[]): Promise<BatchResult<null, TransferAutoDistributionError>> =>
+        (await send("PUT", "/api/transfer_auto_distributions/batch", values)).json(),
+    deleteBatch: async (ids: RecordId[]): Promise<BatchResult<null, null>> =>
+        (await send("DELETE", "/api/transfer_auto_distributions/batch", ids)).json(),
+};
+
+/** Routes of the `transaction_auto_distributions` table. */
+export const transaction_auto_distributions = {
+    address: "/api/transaction_auto_distributions",
+    all: async (): Promise<TransactionAutoDistribution[]> => (await send("GET", "/api/transaction_auto_distributions/all")).json(),
+    insert: async (value: TransactionAutoDistribution): Promise<RecordId> => (await send("POST", "/api/transaction_auto_distributions", value)).json(),
+    update: async (id: RecordId, data: TransactionAutoDistribution): Promise<void> => {
+        await send("PUT", "/api/transaction_auto_distributions", { id, data });
+    },
+    delete: async (id: RecordId): Promise<void> => {
+        await send("DELETE", "/api/transaction_auto_distributions", id);
+    },
+    insertBatch: async (values: TransactionAutoDistribution[]): Promise<BatchResult<RecordId, TransactionAutoDistributionError>> =>
+        (await send("POST", "/api/transaction_auto_distributions/batch", values)).json(),
+    updateBatch: async (values: { id: RecordId; data: TransactionAutoDistribution }[]): Promise<BatchResult<null, TransactionAutoDistributionError>> =>
+        (await send("PUT", "/api/transaction_auto_distributions/batch", values)).json(),
+    deleteBatch: async (ids: RecordId[]): Promise<BatchResult<null, null>> =>
+        (await send("DELETE", "/api/transaction_auto_distributions/batch", ids)).json(),
+};
+
+/** The authentication endpoints. Successful calls set or remove the session cookies and resolve to `undefined`. */
+export const auth = {
+    login: async (creds: Creds): Promise<ClientError | undefined> =>
+        sessionResult(await send("POST", "/login", creds)),
+    register: async (data: Register): Promise<ClientError | RegisterError | undefined> =>
+        sessionResult(await send("POST", "/register", data)),
+    logout: async (): Promise<ClientError | undefined> => sessionResult(await send("POST", "/logout")),
+    refresh: async (): Promise<ClientError | undefined> => sessionResult(await send("POST", "/refresh")),
+    /** Data of the user of the session, without the password. */
+    me: async (): Promise<Omit<Register, "password"> | { Err: ClientError }> => (await send("GET", "/me")).json(),
+};
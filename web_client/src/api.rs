@@ -0,0 +1,2083 @@
+//! Client of the API, generated from the entities of the server by
+//! `cargo run -p server --bin generate_clients`. Regenerate it instead of editing it.
+// The errors of `validate()` have the layout of the errors of the server.
+#![allow(dead_code, clippy::result_large_err)]
+
+use actix_surreal_types::validators::DefaultValidations;
+use actix_surreal_types::ClientError;
+use chrono::{DateTime, Utc};
+use gloo_net::http::{RequestBuilder, Response};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use web_sys::RequestCredentials;
+
+pub use gloo_net::http::Request;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordId {
+    pub tb: String,
+    /// The key by kind, e.g. `{"String": "abc"}`.
+    pub id: serde_json::Value,
+}
+
+/// A validation error of the server, e.g. `{"code": "StringTooShort", "params": {"min": 8}}`,
+/// rendered by `actix_surreal_types::MessageCatalog`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ValidationError {
+    pub code: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub params: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+impl From<actix_surreal_types::validators::ValidationError> for ValidationError {
+    fn from(error: actix_surreal_types::validators::ValidationError) -> Self {
+        let value = serde_json::to_value(&error).unwrap_or_default();
+        serde_json::from_value(value).unwrap_or_else(|_| Self { code: error.to_string(), params: None })
+    }
+}
+
+/// Runs the validators shared with the server, see `validate()` of the entities.
+struct Validator;
+impl DefaultValidations for Validator {}
+
+/// Installs the password policies of the server, which the `password` validators of
+/// `validate()` check against. Until then, they fail with `UnknownPasswordPolicy`.
+pub async fn install_password_policies() -> Result<(), ApiError> {
+    let policies: std::collections::HashMap<String, actix_surreal_types::PasswordPolicy> =
+        send(Request::get("/password_policies")).await?.json().await?;
+    for (name, policy) in policies {
+        policy.install(&name);
+    }
+    Ok(())
+}
+
+#[derive(Debug)]
+pub enum ApiError {
+    Net(gloo_net::Error),
+    /// The status and the body of an unsuccessful response.
+    Status(u16, String),
+}
+
+impl From<gloo_net::Error> for ApiError {
+    fn from(value: gloo_net::Error) -> Self {
+        Self::Net(value)
+    }
+}
+
+/// The reason a single item of a batch was not written.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub enum BatchItemError<E> {
+    Invalid(E),
+    Unauthorized,
+    /// The item itself is fine, but other items of the batch were rejected.
+    RolledBack,
+}
+
+pub type BatchResult<T, E> = Vec<Result<T, BatchItemError<E>>>;
+
+#[derive(Serialize)]
+struct WithId<'a, T> {
+    id: &'a RecordId,
+    data: &'a T,
+}
+
+async fn send(request: RequestBuilder) -> Result<Response, ApiError> {
+    checked(request.credentials(RequestCredentials::Include).send().await?).await
+}
+
+async fn send_json(request: RequestBuilder, body: &(impl Serialize + ?Sized)) -> Result<Response, ApiError> {
+    checked(request.credentials(RequestCredentials::Include).json(body)?.send().await?).await
+}
+
+async fn checked(response: Response) -> Result<Response, ApiError> {
+    match response.ok() {
+        true => Ok(response),
+        false => Err(ApiError::Status(response.status(), response.text().await?)),
+    }
+}
+
+/// Successful authentication calls respond with an empty body, failed ones with `{"Err": ...}`.
+async fn session_result<E: DeserializeOwned>(response: Response) -> Result<Result<(), E>, ApiError> {
+    let body = response.text().await?;
+    if body.is_empty() {
+        return Ok(Ok(()));
+    }
+    serde_json::from_str(&body).map_err(|e| ApiError::Status(response.status(), e.to_string()))
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct User {
+    pub email: String,
+    pub username: String,
+    pub password: String,
+    /// Set by the server.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub registration_date: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub selected_preference: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UserError {
+    pub email: Vec<ValidationError>,
+    pub username: Vec<ValidationError>,
+    pub password: Vec<ValidationError>,
+    pub registration_date: Vec<ValidationError>,
+    pub selected_preference: Vec<ValidationError>,
+    pub _entity: Vec<ValidationError>,
+}
+
+impl User {
+    /// The validations the server runs that don't need the server.
+    pub fn validate(&self) -> Result<(), UserError> {
+        let mut erronous = false;
+        let mut result = UserError {
+            email: Vec::new(),
+            username: Vec::new(),
+            password: Vec::new(),
+            registration_date: Vec::new(),
+            selected_preference: Vec::new(),
+            _entity: Vec::new(),
+        };
+        if let Err(e) = <Validator as DefaultValidations>::email_format(&self.email) {
+            result.email.push(e.into());
+            erronous = true;
+        }
+        if let Err(e) = <Validator as DefaultValidations>::not_empty(&self.username) {
+            result.username.push(e.into());
+            erronous = true;
+        }
+        if let Err(e) = <Validator as DefaultValidations>::password((&self.password, "users")) {
+            result.password.push(e.into());
+            erronous = true;
+        }
+        if let Err(e) = <Validator as DefaultValidations>::password_unlike((&self.password, "users", &self.email)).map_err(|e| actix_surreal_types::validators::ComparedFields::compared_to(e, &["email"])) {
+            result.password.push(e.into());
+            erronous = true;
+        }
+        if let Err(e) = <Validator as DefaultValidations>::password_unlike((&self.password, "users", &self.username)).map_err(|e| actix_surreal_types::validators::ComparedFields::compared_to(e, &["username"])) {
+            result.password.push(e.into());
+            erronous = true;
+        }
+        match erronous {
+            true => Err(result),
+            false => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Account {
+    pub title: String,
+    /// The user of the session, set by the server.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<RecordId>,
+    pub currency_id: RecordId,
+    /// Set by the server.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub balance: Option<i64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AccountError {
+    pub title: Vec<ValidationError>,
+    pub user_id: Vec<ValidationError>,
+    pub currency_id: Vec<ValidationError>,
+    pub balance: Vec<ValidationError>,
+    pub _entity: Vec<ValidationError>,
+}
+
+impl Account {
+    /// The validations the server runs that don't need the server.
+    pub fn validate(&self) -> Result<(), AccountError> {
+        let mut erronous = false;
+        let mut result = AccountError {
+            title: Vec::new(),
+            user_id: Vec::new(),
+            currency_id: Vec::new(),
+            balance: Vec::new(),
+            _entity: Vec::new(),
+        };
+        if let Err(e) = <Validator as DefaultValidations>::not_empty(&self.title) {
+            result.title.push(e.into());
+            erronous = true;
+        }
+        match erronous {
+            true => Err(result),
+            false => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Register {
+    pub username: String,
+    pub email: String,
+    pub password: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RegisterError {
+    pub username: Vec<ValidationError>,
+    pub email: Vec<ValidationError>,
+    pub password: Vec<ValidationError>,
+    pub _entity: Vec<ValidationError>,
+}
+
+impl Register {
+    /// The validations the server runs that don't need the server.
+    pub fn validate(&self) -> Result<(), RegisterError> {
+        let mut erronous = false;
+        let mut result = RegisterError {
+            username: Vec::new(),
+            email: Vec::new(),
+            password: Vec::new(),
+            _entity: Vec::new(),
+        };
+        if let Err(e) = <Validator as DefaultValidations>::not_empty(&self.username) {
+            result.username.push(e.into());
+            erronous = true;
+        }
+        if let Err(e) = <Validator as DefaultValidations>::email_format(&self.email) {
+            result.email.push(e.into());
+            erronous = true;
+        }
+        if let Err(e) = <Validator as DefaultValidations>::password((&self.password, "users")) {
+            result.password.push(e.into());
+            erronous = true;
+        }
+        if let Err(e) = <Validator as DefaultValidations>::password_unlike((&self.password, "users", &self.email)).map_err(|e| actix_surreal_types::validators::ComparedFields::compared_to(e, &["email"])) {
+            result.password.push(e.into());
+            erronous = true;
+        }
+        if let Err(e) = <Validator as DefaultValidations>::password_unlike((&self.password, "users", &self.username)).map_err(|e| actix_surreal_types::validators::ComparedFields::compared_to(e, &["username"])) {
+            result.password.push(e.into());
+            erronous = true;
+        }
+        match erronous {
+            true => Err(result),
+            false => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Creds {
+    pub email: String,
+    pub password: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CredsError {
+    pub email: Vec<ValidationError>,
+    pub password: Vec<ValidationError>,
+    pub _entity: Vec<ValidationError>,
+}
+
+impl Creds {
+    /// The validations the server runs that don't need the server.
+    pub fn validate(&self) -> Result<(), CredsError> {
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Tag {
+    /// The user of the session, set by the server.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<RecordId>,
+    pub metadata_id: RecordId,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TagError {
+    pub user_id: Vec<ValidationError>,
+    pub metadata_id: Vec<ValidationError>,
+    pub _entity: Vec<ValidationError>,
+}
+
+impl Tag {
+    /// The validations the server runs that don't need the server.
+    pub fn validate(&self) -> Result<(), TagError> {
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MetadataTag {
+    pub metadata_id: RecordId,
+    pub tag_id: RecordId,
+    pub exception: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MetadataTagError {
+    pub metadata_id: Vec<ValidationError>,
+    pub tag_id: Vec<ValidationError>,
+    pub exception: Vec<ValidationError>,
+    pub _entity: Vec<ValidationError>,
+}
+
+impl MetadataTag {
+    /// The validations the server runs that don't need the server.
+    pub fn validate(&self) -> Result<(), MetadataTagError> {
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TagGroup {
+    /// The user of the session, set by the server.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<RecordId>,
+    #[serde(default)]
+    pub metadata_id: Option<RecordId>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TagGroupError {
+    pub user_id: Vec<ValidationError>,
+    pub metadata_id: Vec<ValidationError>,
+    pub _entity: Vec<ValidationError>,
+}
+
+impl TagGroup {
+    /// The validations the server runs that don't need the server.
+    pub fn validate(&self) -> Result<(), TagGroupError> {
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TagGroupTag {
+    pub tag_group_id: RecordId,
+    pub tag_id: RecordId,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TagGroupTagError {
+    pub tag_group_id: Vec<ValidationError>,
+    pub tag_id: Vec<ValidationError>,
+    pub _entity: Vec<ValidationError>,
+}
+
+impl TagGroupTag {
+    /// The validations the server runs that don't need the server.
+    pub fn validate(&self) -> Result<(), TagGroupTagError> {
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MetadataTagGroup {
+    pub metadata_id: RecordId,
+    pub tag_group_id: RecordId,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MetadataTagGroupError {
+    pub metadata_id: Vec<ValidationError>,
+    pub tag_group_id: Vec<ValidationError>,
+    pub _entity: Vec<ValidationError>,
+}
+
+impl MetadataTagGroup {
+    /// The validations the server runs that don't need the server.
+    pub fn validate(&self) -> Result<(), MetadataTagGroupError> {
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FinancialGoal {
+    /// The user of the session, set by the server.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<RecordId>,
+    pub currency_id: RecordId,
+    pub start_date: DateTime<Utc>,
+    pub end_date: DateTime<Utc>,
+    pub target_income: i64,
+    pub metadata_id: RecordId,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FinancialGoalError {
+    pub user_id: Vec<ValidationError>,
+    pub currency_id: Vec<ValidationError>,
+    pub start_date: Vec<ValidationError>,
+    pub end_date: Vec<ValidationError>,
+    pub target_income: Vec<ValidationError>,
+    pub metadata_id: Vec<ValidationError>,
+    pub _entity: Vec<ValidationError>,
+}
+
+impl FinancialGoal {
+    /// The validations the server runs that don't need the server.
+    pub fn validate(&self) -> Result<(), FinancialGoalError> {
+        let mut erronous = false;
+        let mut result = FinancialGoalError {
+            user_id: Vec::new(),
+            currency_id: Vec::new(),
+            start_date: Vec::new(),
+            end_date: Vec::new(),
+            target_income: Vec::new(),
+            metadata_id: Vec::new(),
+            _entity: Vec::new(),
+        };
+        if let Err(e) = <Validator as DefaultValidations>::v1_gt_v2((&self.start_date, &self.end_date)).map_err(|e| actix_surreal_types::validators::ComparedFields::compared_to(e, &["end_date"])) {
+            result.start_date.push(e.into());
+            erronous = true;
+        }
+        if let Err(e) = <Validator as DefaultValidations>::gt_zero(&self.target_income) {
+            result.target_income.push(e.into());
+            erronous = true;
+        }
+        match erronous {
+            true => Err(result),
+            false => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Transaction {
+    pub account_id: RecordId,
+    pub amount: i64,
+    pub date: DateTime<Utc>,
+    pub metadata_id: RecordId,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TransactionError {
+    pub account_id: Vec<ValidationError>,
+    pub amount: Vec<ValidationError>,
+    pub date: Vec<ValidationError>,
+    pub metadata_id: Vec<ValidationError>,
+    pub _entity: Vec<ValidationError>,
+}
+
+impl Transaction {
+    /// The validations the server runs that don't need the server.
+    pub fn validate(&self) -> Result<(), TransactionError> {
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Transfer {
+    pub account_from: RecordId,
+    pub account_to: RecordId,
+    pub amount_from: i64,
+    pub amount_to: i64,
+    pub conversion_rate: f64,
+    pub fee: f64,
+    pub metadata_id: RecordId,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TransferError {
+    pub account_from: Vec<ValidationError>,
+    pub account_to: Vec<ValidationError>,
+    pub amount_from: Vec<ValidationError>,
+    pub amount_to: Vec<ValidationError>,
+    pub conversion_rate: Vec<ValidationError>,
+    pub fee: Vec<ValidationError>,
+    pub metadata_id: Vec<ValidationError>,
+    pub _entity: Vec<ValidationError>,
+}
+
+impl Transfer {
+    /// The validations the server runs that don't need the server.
+    pub fn validate(&self) -> Result<(), TransferError> {
+        let mut erronous = false;
+        let mut result = TransferError {
+            account_from: Vec::new(),
+            account_to: Vec::new(),
+            amount_from: Vec::new(),
+            amount_to: Vec::new(),
+            conversion_rate: Vec::new(),
+            fee: Vec::new(),
+            metadata_id: Vec::new(),
+            _entity: Vec::new(),
+        };
+        if let Err(e) = <Validator as DefaultValidations>::gt_zero(&self.amount_from) {
+            result.amount_from.push(e.into());
+            erronous = true;
+        }
+        if let Err(e) = <Validator as DefaultValidations>::gt_zero(&self.amount_to) {
+            result.amount_to.push(e.into());
+            erronous = true;
+        }
+        if let Err(e) = <Validator as DefaultValidations>::finite(&self.conversion_rate) {
+            result.conversion_rate.push(e.into());
+            erronous = true;
+        }
+        if let Err(e) = <Validator as DefaultValidations>::gt_zero(&self.conversion_rate) {
+            result.conversion_rate.push(e.into());
+            erronous = true;
+        }
+        if let Err(e) = <Validator as DefaultValidations>::finite(&self.fee) {
+            result.fee.push(e.into());
+            erronous = true;
+        }
+        if let Err(e) = <Validator as DefaultValidations>::gt_zero(&self.fee) {
+            result.fee.push(e.into());
+            erronous = true;
+        }
+        match erronous {
+            true => Err(result),
+            false => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StableIncome {
+    /// The user of the session, set by the server.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<RecordId>,
+    pub currency_id: RecordId,
+    pub amount_per_month: i64,
+    pub start_date: DateTime<Utc>,
+    #[serde(default)]
+    pub end_date: Option<DateTime<Utc>>,
+    /// Set by the server.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_update_date: Option<DateTime<Utc>>,
+    pub metadata_id: RecordId,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StableIncomeError {
+    pub user_id: Vec<ValidationError>,
+    pub currency_id: Vec<ValidationError>,
+    pub amount_per_month: Vec<ValidationError>,
+    pub start_date: Vec<ValidationError>,
+    pub end_date: Vec<ValidationError>,
+    pub last_update_date: Vec<ValidationError>,
+    pub metadata_id: Vec<ValidationError>,
+    pub _entity: Vec<ValidationError>,
+}
+
+impl StableIncome {
+    /// The validations the server runs that don't need the server.
+    pub fn validate(&self) -> Result<(), StableIncomeError> {
+        let mut erronous = false;
+        let mut result = StableIncomeError {
+            user_id: Vec::new(),
+            currency_id: Vec::new(),
+            amount_per_month: Vec::new(),
+            start_date: Vec::new(),
+            end_date: Vec::new(),
+            last_update_date: Vec::new(),
+            metadata_id: Vec::new(),
+            _entity: Vec::new(),
+        };
+        if let Err(e) = <Validator as DefaultValidations>::ne_zero(&self.amount_per_month) {
+            result.amount_per_month.push(e.into());
+            erronous = true;
+        }
+        if let Err(e) = <Validator as DefaultValidations>::optional_v2_gt_v1((&self.start_date, &self.end_date)).map_err(|e| actix_surreal_types::validators::ComparedFields::compared_to(e, &["end_date"])) {
+            result.start_date.push(e.into());
+            erronous = true;
+        }
+        match erronous {
+            true => Err(result),
+            false => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StableIncomeIncome {
+    pub stable_income_id: RecordId,
+    pub transaction_id: RecordId,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StableIncomeIncomeError {
+    pub stable_income_id: Vec<ValidationError>,
+    pub transaction_id: Vec<ValidationError>,
+    pub _entity: Vec<ValidationError>,
+}
+
+impl StableIncomeIncome {
+    /// The validations the server runs that don't need the server.
+    pub fn validate(&self) -> Result<(), StableIncomeIncomeError> {
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Loan {
+    /// The user of the session, set by the server.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<RecordId>,
+    pub currency_id: RecordId,
+    pub principal_amount: i64,
+    pub interest_rate: f64,
+    pub start_date: DateTime<Utc>,
+    #[serde(default)]
+    pub end_date: Option<DateTime<Utc>>,
+    pub interest_rate_type: String,
+    pub compounding_frequency: String,
+    pub metadata_id: RecordId,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LoanError {
+    pub user_id: Vec<ValidationError>,
+    pub currency_id: Vec<ValidationError>,
+    pub principal_amount: Vec<ValidationError>,
+    pub interest_rate: Vec<ValidationError>,
+    pub start_date: Vec<ValidationError>,
+    pub end_date: Vec<ValidationError>,
+    pub interest_rate_type: Vec<ValidationError>,
+    pub compounding_frequency: Vec<ValidationError>,
+    pub metadata_id: Vec<ValidationError>,
+    pub _entity: Vec<ValidationError>,
+}
+
+impl Loan {
+    /// The validations the server runs that don't need the server.
+    pub fn validate(&self) -> Result<(), LoanError> {
+        let mut erronous = false;
+        let mut result = LoanError {
+            user_id: Vec::new(),
+            currency_id: Vec::new(),
+            principal_amount: Vec::new(),
+            interest_rate: Vec::new(),
+            start_date: Vec::new(),
+            end_date: Vec::new(),
+            interest_rate_type: Vec::new(),
+            compounding_frequency: Vec::new(),
+            metadata_id: Vec::new(),
+            _entity: Vec::new(),
+        };
+        if let Err(e) = <Validator as DefaultValidations>::gt_zero(&self.principal_amount) {
+            result.principal_amount.push(e.into());
+            erronous = true;
+        }
+        if let Err(e) = <Validator as DefaultValidations>::finite(&self.interest_rate) {
+            result.interest_rate.push(e.into());
+            erronous = true;
+        }
+        if let Err(e) = <Validator as DefaultValidations>::gt_zero(&self.interest_rate) {
+            result.interest_rate.push(e.into());
+            erronous = true;
+        }
+        if let Err(e) = <Validator as DefaultValidations>::optional_v2_gt_v1((&self.start_date, &self.end_date)).map_err(|e| actix_surreal_types::validators::ComparedFields::compared_to(e, &["end_date"])) {
+            result.start_date.push(e.into());
+            erronous = true;
+        }
+        if let Err(e) = <Validator as DefaultValidations>::one_of((&self.interest_rate_type, &["simple", "compound"])) {
+            result.interest_rate_type.push(e.into());
+            erronous = true;
+        }
+        if let Err(e) = <Validator as DefaultValidations>::one_of((&self.compounding_frequency, &["annually", "semi_annually", "quarterly", "monthly", "daily"])) {
+            result.compounding_frequency.push(e.into());
+            erronous = true;
+        }
+        match erronous {
+            true => Err(result),
+            false => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LoanPayment {
+    pub loan_id: RecordId,
+    pub transaction_id: RecordId,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LoanPaymentError {
+    pub loan_id: Vec<ValidationError>,
+    pub transaction_id: Vec<ValidationError>,
+    pub _entity: Vec<ValidationError>,
+}
+
+impl LoanPayment {
+    /// The validations the server runs that don't need the server.
+    pub fn validate(&self) -> Result<(), LoanPaymentError> {
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Investment {
+    /// The user of the session, set by the server.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<RecordId>,
+    pub currency_id: RecordId,
+    pub r#type: String,
+    pub compounding_frequency: String,
+    pub principal_amount: i64,
+    pub start_date: DateTime<Utc>,
+    #[serde(default)]
+    pub expected_end_date: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub end_date: Option<DateTime<Utc>>,
+    pub risk_level: String,
+    pub expected_return: f64,
+    pub metadata_id: RecordId,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InvestmentError {
+    pub user_id: Vec<ValidationError>,
+    pub currency_id: Vec<ValidationError>,
+    pub r#type: Vec<ValidationError>,
+    pub compounding_frequency: Vec<ValidationError>,
+    pub principal_amount: Vec<ValidationError>,
+    pub start_date: Vec<ValidationError>,
+    pub expected_end_date: Vec<ValidationError>,
+    pub end_date: Vec<ValidationError>,
+    pub risk_level: Vec<ValidationError>,
+    pub expected_return: Vec<ValidationError>,
+    pub metadata_id: Vec<ValidationError>,
+    pub _entity: Vec<ValidationError>,
+}
+
+impl Investment {
+    /// The validations the server runs that don't need the server.
+    pub fn validate(&self) -> Result<(), InvestmentError> {
+        let mut erronous = false;
+        let mut result = InvestmentError {
+            user_id: Vec::new(),
+            currency_id: Vec::new(),
+            r#type: Vec::new(),
+            compounding_frequency: Vec::new(),
+            principal_amount: Vec::new(),
+            start_date: Vec::new(),
+            expected_end_date: Vec::new(),
+            end_date: Vec::new(),
+            risk_level: Vec::new(),
+            expected_return: Vec::new(),
+            metadata_id: Vec::new(),
+            _entity: Vec::new(),
+        };
+        if let Err(e) = <Validator as DefaultValidations>::one_of((&self.compounding_frequency, &["annually", "semi_annually", "quarterly", "monthly", "daily"])) {
+            result.compounding_frequency.push(e.into());
+            erronous = true;
+        }
+        if let Err(e) = <Validator as DefaultValidations>::gt_zero(&self.principal_amount) {
+            result.principal_amount.push(e.into());
+            erronous = true;
+        }
+        if let Err(e) = <Validator as DefaultValidations>::optional_v2_gt_v1((&self.start_date, &self.expected_end_date)).map_err(|e| actix_surreal_types::validators::ComparedFields::compared_to(e, &["expected_end_date"])) {
+            result.start_date.push(e.into());
+            erronous = true;
+        }
+        if let Err(e) = <Validator as DefaultValidations>::optional_v2_gt_v1((&self.start_date, &self.end_date)).map_err(|e| actix_surreal_types::validators::ComparedFields::compared_to(e, &["end_date"])) {
+            result.start_date.push(e.into());
+            erronous = true;
+        }
+        if let Err(e) = <Validator as DefaultValidations>::one_of((&self.risk_level, &["low", "moderate", "high", "very_high"])) {
+            result.risk_level.push(e.into());
+            erronous = true;
+        }
+        if let Err(e) = <Validator as DefaultValidations>::finite(&self.expected_return) {
+            result.expected_return.push(e.into());
+            erronous = true;
+        }
+        if let Err(e) = <Validator as DefaultValidations>::gt_zero(&self.expected_return) {
+            result.expected_return.push(e.into());
+            erronous = true;
+        }
+        match erronous {
+            true => Err(result),
+            false => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InvestmentReturn {
+    pub investment_id: RecordId,
+    pub transaction_id: RecordId,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InvestmentReturnError {
+    pub investment_id: Vec<ValidationError>,
+    pub transaction_id: Vec<ValidationError>,
+    pub _entity: Vec<ValidationError>,
+}
+
+impl InvestmentReturn {
+    /// The validations the server runs that don't need the server.
+    pub fn validate(&self) -> Result<(), InvestmentReturnError> {
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Metadata {
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// The user of the session, set by the server.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<RecordId>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MetadataError {
+    pub title: Vec<ValidationError>,
+    pub description: Vec<ValidationError>,
+    pub user_id: Vec<ValidationError>,
+    pub _entity: Vec<ValidationError>,
+}
+
+impl Metadata {
+    /// The validations the server runs that don't need the server.
+    pub fn validate(&self) -> Result<(), MetadataError> {
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Preference {
+    /// The user of the session, set by the server.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<RecordId>,
+    pub default_currency_id: RecordId,
+    pub language: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PreferenceError {
+    pub user_id: Vec<ValidationError>,
+    pub default_currency_id: Vec<ValidationError>,
+    pub language: Vec<ValidationError>,
+    pub _entity: Vec<ValidationError>,
+}
+
+impl Preference {
+    /// The validations the server runs that don't need the server.
+    pub fn validate(&self) -> Result<(), PreferenceError> {
+        let mut erronous = false;
+        let mut result = PreferenceError {
+            user_id: Vec::new(),
+            default_currency_id: Vec::new(),
+            language: Vec::new(),
+            _entity: Vec::new(),
+        };
+        if let Err(e) = <Validator as DefaultValidations>::bcp47_language(&self.language) {
+            result.language.push(e.into());
+            erronous = true;
+        }
+        match erronous {
+            true => Err(result),
+            false => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AutoDistribution {
+    pub ratio: f64,
+    pub account_id: RecordId,
+    pub metadata_id: RecordId,
+    pub record_metadata_id: RecordId,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AutoDistributionError {
+    pub ratio: Vec<ValidationError>,
+    pub account_id: Vec<ValidationError>,
+    pub metadata_id: Vec<ValidationError>,
+    pub record_metadata_id: Vec<ValidationError>,
+    pub _entity: Vec<ValidationError>,
+}
+
+impl AutoDistribution {
+    /// The validations the server runs that don't need the server.
+    pub fn validate(&self) -> Result<(), AutoDistributionError> {
+        let mut erronous = false;
+        let mut result = AutoDistributionError {
+            ratio: Vec::new(),
+            account_id: Vec::new(),
+            metadata_id: Vec::new(),
+            record_metadata_id: Vec::new(),
+            _entity: Vec::new(),
+        };
+        if let Err(e) = <Validator as DefaultValidations>::finite(&self.ratio) {
+            result.ratio.push(e.into());
+            erronous = true;
+        }
+        if let Err(e) = <Validator as DefaultValidations>::gt_zero(&self.ratio) {
+            result.ratio.push(e.into());
+            erronous = true;
+        }
+        match erronous {
+            true => Err(result),
+            false => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FinancialGoalAutoDistribution {
+    pub financial_goal_id: RecordId,
+    pub auto_distribution_id: RecordId,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FinancialGoalAutoDistributionError {
+    pub financial_goal_id: Vec<ValidationError>,
+    pub auto_distribution_id: Vec<ValidationError>,
+    pub _entity: Vec<ValidationError>,
+}
+
+impl FinancialGoalAutoDistribution {
+    /// The validations the server runs that don't need the server.
+    pub fn validate(&self) -> Result<(), FinancialGoalAutoDistributionError> {
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StableIncomeAutoDistribution {
+    pub stable_income_id: RecordId,
+    pub auto_distribution_id: RecordId,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StableIncomeAutoDistributionError {
+    pub stable_income_id: Vec<ValidationError>,
+    pub auto_distribution_id: Vec<ValidationError>,
+    pub _entity: Vec<ValidationError>,
+}
+
+impl StableIncomeAutoDistribution {
+    /// The validations the server runs that don't need the server.
+    pub fn validate(&self) -> Result<(), StableIncomeAutoDistributionError> {
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LoanAutoDistribution {
+    pub loan_id: RecordId,
+    pub auto_distribution_id: RecordId,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LoanAutoDistributionError {
+    pub loan_id: Vec<ValidationError>,
+    pub auto_distribution_id: Vec<ValidationError>,
+    pub _entity: Vec<ValidationError>,
+}
+
+impl LoanAutoDistribution {
+    /// The validations the server runs that don't need the server.
+    pub fn validate(&self) -> Result<(), LoanAutoDistributionError> {
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InvestmentAutoDistribution {
+    pub investment_id: RecordId,
+    pub auto_distribution_id: RecordId,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InvestmentAutoDistributionError {
+    pub investment_id: Vec<ValidationError>,
+    pub auto_distribution_id: Vec<ValidationError>,
+    pub _entity: Vec<ValidationError>,
+}
+
+impl InvestmentAutoDistribution {
+    /// The validations the server runs that don't need the server.
+    pub fn validate(&self) -> Result<(), InvestmentAutoDistributionError> {
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TransferAutoDistribution {
+    pub metadata_id: RecordId,
+    pub account_to: String,
+    pub auto_distribution_id: RecordId,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TransferAutoDistributionError {
+    pub metadata_id: Vec<ValidationError>,
+    pub account_to: Vec<ValidationError>,
+    pub auto_distribution_id: Vec<ValidationError>,
+    pub _entity: Vec<ValidationError>,
+}
+
+impl TransferAutoDistribution {
+    /// The validations the server runs that don't need the server.
+    pub fn validate(&self) -> Result<(), TransferAutoDistributionError> {
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TransactionAutoDistribution {
+    pub auto_distribution_id: RecordId,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TransactionAutoDistributionError {
+    pub auto_distribution_id: Vec<ValidationError>,
+    pub _entity: Vec<ValidationError>,
+}
+
+impl TransactionAutoDistribution {
+    /// The validations the server runs that don't need the server.
+    pub fn validate(&self) -> Result<(), TransactionAutoDistributionError> {
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FinancialGoalAllocations {
+    pub financial_goal_id: RecordId,
+    #[serde(default)]
+    pub account_id: Option<String>,
+    pub date: DateTime<Utc>,
+    pub amount: i64,
+    pub metadata_id: RecordId,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FinancialGoalAllocationsError {
+    pub financial_goal_id: Vec<ValidationError>,
+    pub account_id: Vec<ValidationError>,
+    pub date: Vec<ValidationError>,
+    pub amount: Vec<ValidationError>,
+    pub metadata_id: Vec<ValidationError>,
+    pub _entity: Vec<ValidationError>,
+}
+
+impl FinancialGoalAllocations {
+    /// The validations the server runs that don't need the server.
+    pub fn validate(&self) -> Result<(), FinancialGoalAllocationsError> {
+        let mut erronous = false;
+        let mut result = FinancialGoalAllocationsError {
+            financial_goal_id: Vec::new(),
+            account_id: Vec::new(),
+            date: Vec::new(),
+            amount: Vec::new(),
+            metadata_id: Vec::new(),
+            _entity: Vec::new(),
+        };
+        if let Err(e) = <Validator as DefaultValidations>::gt_zero(&self.amount) {
+            result.amount.push(e.into());
+            erronous = true;
+        }
+        match erronous {
+            true => Err(result),
+            false => Ok(()),
+        }
+    }
+}
+
+/// Routes of the `accounts` table.
+pub mod accounts {
+    use super::*;
+
+    pub const ADDRESS: &str = "/api/accounts";
+
+    pub async fn all() -> Result<Vec<Account>, ApiError> {
+        Ok(send(Request::get("/api/accounts/all")).await?.json().await?)
+    }
+
+    pub async fn insert(value: &Account) -> Result<RecordId, ApiError> {
+        Ok(send_json(Request::post(ADDRESS), value).await?.json().await?)
+    }
+
+    pub async fn update(id: &RecordId, data: &Account) -> Result<(), ApiError> {
+        send_json(Request::put(ADDRESS), &WithId { id, data }).await?;
+        Ok(())
+    }
+
+    pub async fn delete(id: &RecordId) -> Result<(), ApiError> {
+        send_json(Request::delete(ADDRESS), id).await?;
+        Ok(())
+    }
+
+    pub async fn insert_batch(values: &[Account]) -> Result<BatchResult<RecordId, AccountError>, ApiError> {
+        Ok(send_json(Request::post("/api/accounts/batch"), values).await?.json().await?)
+    }
+
+    pub async fn update_batch(values: &[(RecordId, Account)]) -> Result<BatchResult<(), AccountError>, ApiError> {
+        let values: Vec<WithId<Account>> = values.iter().map(|(id, data)| WithId { id, data }).collect();
+        Ok(send_json(Request::put("/api/accounts/batch"), &values).await?.json().await?)
+    }
+
+    pub async fn delete_batch(ids: &[RecordId]) -> Result<BatchResult<(), ()>, ApiError> {
+        Ok(send_json(Request::delete("/api/accounts/batch"), ids).await?.json().await?)
+    }
+
+    pub async fn restore(id: &RecordId) -> Result<(), ApiError> {
+        send_json(Request::post("/api/accounts/restore"), id).await?;
+        Ok(())
+    }
+
+    pub async fn trash() -> Result<Vec<Account>, ApiError> {
+        Ok(send(Request::get("/api/accounts/trash")).await?.json().await?)
+    }
+
+    /// The changes of the record, oldest first.
+    pub async fn history(id: &str) -> Result<Vec<serde_json::Value>, ApiError> {
+        Ok(send(Request::get("/api/accounts/history").query([("id", id)])).await?.json().await?)
+    }
+}
+
+/// Routes of the `tags` table.
+pub mod tags {
+    use super::*;
+
+    pub const ADDRESS: &str = "/api/tags";
+
+    pub async fn all() -> Result<Vec<Tag>, ApiError> {
+        Ok(send(Request::get("/api/tags/all")).await?.json().await?)
+    }
+
+    pub async fn insert(value: &Tag) -> Result<RecordId, ApiError> {
+        Ok(send_json(Request::post(ADDRESS), value).await?.json().await?)
+    }
+
+    pub async fn update(id: &RecordId, data: &Tag) -> Result<(), ApiError> {
+        send_json(Request::put(ADDRESS), &WithId { id, data }).await?;
+        Ok(())
+    }
+
+    pub async fn delete(id: &RecordId) -> Result<(), ApiError> {
+        send_json(Request::delete(ADDRESS), id).await?;
+        Ok(())
+    }
+
+    pub async fn insert_batch(values: &[Tag]) -> Result<BatchResult<RecordId, TagError>, ApiError> {
+        Ok(send_json(Request::post("/api/tags/batch"), values).await?.json().await?)
+    }
+
+    pub async fn update_batch(values: &[(RecordId, Tag)]) -> Result<BatchResult<(), TagError>, ApiError> {
+        let values: Vec<WithId<Tag>> = values.iter().map(|(id, data)| WithId { id, data }).collect();
+        Ok(send_json(Request::put("/api/tags/batch"), &values).await?.json().await?)
+    }
+
+    pub async fn delete_batch(ids: &[RecordId]) -> Result<BatchResult<(), ()>, ApiError> {
+        Ok(send_json(Request::delete("/api/tags/batch"), ids).await?.json().await?)
+    }
+}
+
+/// Routes of the `metadata_tags` table.
+pub mod metadata_tags {
+    use super::*;
+
+    pub const ADDRESS: &str = "/api/metadata_tags";
+
+    pub async fn all() -> Result<Vec<MetadataTag>, ApiError> {
+        Ok(send(Request::get("/api/metadata_tags/all")).await?.json().await?)
+    }
+
+    pub async fn insert(value: &MetadataTag) -> Result<RecordId, ApiError> {
+        Ok(send_json(Request::post(ADDRESS), value).await?.json().await?)
+    }
+
+    pub async fn update(id: &RecordId, data: &MetadataTag) -> Result<(), ApiError> {
+        send_json(Request::put(ADDRESS), &WithId { id, data }).await?;
+        Ok(())
+    }
+
+    pub async fn delete(id: &RecordId) -> Result<(), ApiError> {
+        send_json(Request::delete(ADDRESS), id).await?;
+        Ok(())
+    }
+
+    pub async fn insert_batch(values: &[MetadataTag]) -> Result<BatchResult<RecordId, MetadataTagError>, ApiError> {
+        Ok(send_json(Request::post("/api/metadata_tags/batch"), values).await?.json().await?)
+    }
+
+    pub async fn update_batch(values: &[(RecordId, MetadataTag)]) -> Result<BatchResult<(), MetadataTagError>, ApiError> {
+        let values: Vec<WithId<MetadataTag>> = values.iter().map(|(id, data)| WithId { id, data }).collect();
+        Ok(send_json(Request::put("/api/metadata_tags/batch"), &values).await?.json().await?)
+    }
+
+    pub async fn delete_batch(ids: &[RecordId]) -> Result<BatchResult<(), ()>, ApiError> {
+        Ok(send_json(Request::delete("/api/metadata_tags/batch"), ids).await?.json().await?)
+    }
+}
+
+/// Routes of the `tag_groups` table.
+pub mod tag_groups {
+    use super::*;
+
+    pub const ADDRESS: &str = "/api/tag_groups";
+
+    pub async fn all() -> Result<Vec<TagGroup>, ApiError> {
+        Ok(send(Request::get("/api/tag_groups/all")).await?.json().await?)
+    }
+
+    pub async fn insert(value: &TagGroup) -> Result<RecordId, ApiError> {
+        Ok(send_json(Request::post(ADDRESS), value).await?.json().await?)
+    }
+
+    pub async fn update(id: &RecordId, data: &TagGroup) -> Result<(), ApiError> {
+        send_json(Request::put(ADDRESS), &WithId { id, data }).await?;
+        Ok(())
+    }
+
+    pub async fn delete(id: &RecordId) -> Result<(), ApiError> {
+        send_json(Request::delete(ADDRESS), id).await?;
+        Ok(())
+    }
+
+    pub async fn insert_batch(values: &[TagGroup]) -> Result<BatchResult<RecordId, TagGroupError>, ApiError> {
+        Ok(send_json(Request::post("/api/tag_groups/batch"), values).await?.json().await?)
+    }
+
+    pub async fn update_batch(values: &[(RecordId, TagGroup)]) -> Result<BatchResult<(), TagGroupError>, ApiError> {
+        let values: Vec<WithId<TagGroup>> = values.iter().map(|(id, data)| WithId { id, data }).collect();
+        Ok(send_json(Request::put("/api/tag_groups/batch"), &values).await?.json().await?)
+    }
+
+    pub async fn delete_batch(ids: &[RecordId]) -> Result<BatchResult<(), ()>, ApiError> {
+        Ok(send_json(Request::delete("/api/tag_groups/batch"), ids).await?.json().await?)
+    }
+}
+
+/// Routes of the `tag_group_tags` table.
+pub mod tag_group_tags {
+    use super::*;
+
+    pub const ADDRESS: &str = "/api/tag_group_tags";
+
+    pub async fn all() -> Result<Vec<TagGroupTag>, ApiError> {
+        Ok(send(Request::get("/api/tag_group_tags/all")).await?.json().await?)
+    }
+
+    pub async fn insert(value: &TagGroupTag) -> Result<RecordId, ApiError> {
+        Ok(send_json(Request::post(ADDRESS), value).await?.json().await?)
+    }
+
+    pub async fn update(id: &RecordId, data: &TagGroupTag) -> Result<(), ApiError> {
+        send_json(Request::put(ADDRESS), &WithId { id, data }).await?;
+        Ok(())
+    }
+
+    pub async fn delete(id: &RecordId) -> Result<(), ApiError> {
+        send_json(Request::delete(ADDRESS), id).await?;
+        Ok(())
+    }
+
+    pub async fn insert_batch(values: &[TagGroupTag]) -> Result<BatchResult<RecordId, TagGroupTagError>, ApiError> {
+        Ok(send_json(Request::post("/api/tag_group_tags/batch"), values).await?.json().await?)
+    }
+
+    pub async fn update_batch(values: &[(RecordId, TagGroupTag)]) -> Result<BatchResult<(), TagGroupTagError>, ApiError> {
+        let values: Vec<WithId<TagGroupTag>> = values.iter().map(|(id, data)| WithId { id, data }).collect();
+        Ok(send_json(Request::put("/api/tag_group_tags/batch"), &values).await?.json().await?)
+    }
+
+    pub async fn delete_batch(ids: &[RecordId]) -> Result<BatchResult<(), ()>, ApiError> {
+        Ok(send_json(Request::delete("/api/tag_group_tags/batch"), ids).await?.json().await?)
+    }
+}
+
+/// Routes of the `metadata_tag_groups` table.
+pub mod metadata_tag_groups {
+    use super::*;
+
+    pub const ADDRESS: &str = "/api/metadata_tag_groups";
+
+    pub async fn all() -> Result<Vec<MetadataTagGroup>, ApiError> {
+        Ok(send(Request::get("/api/metadata_tag_groups/all")).await?.json().await?)
+    }
+
+    pub async fn insert(value: &MetadataTagGroup) -> Result<RecordId, ApiError> {
+        Ok(send_json(Request::post(ADDRESS), value).await?.json().await?)
+    }
+
+    pub async fn update(id: &RecordId, data: &MetadataTagGroup) -> Result<(), ApiError> {
+        send_json(Request::put(ADDRESS), &WithId { id, data }).await?;
+        Ok(())
+    }
+
+    pub async fn delete(id: &RecordId) -> Result<(), ApiError> {
+        send_json(Request::delete(ADDRESS), id).await?;
+        Ok(())
+    }
+
+    pub async fn insert_batch(values: &[MetadataTagGroup]) -> Result<BatchResult<RecordId, MetadataTagGroupError>, ApiError> {
+        Ok(send_json(Request::post("/api/metadata_tag_groups/batch"), values).await?.json().await?)
+    }
+
+    pub async fn update_batch(values: &[(RecordId, MetadataTagGroup)]) -> Result<BatchResult<(), MetadataTagGroupError>, ApiError> {
+        let values: Vec<WithId<MetadataTagGroup>> = values.iter().map(|(id, data)| WithId { id, data }).collect();
+        Ok(send_json(Request::put("/api/metadata_tag_groups/batch"), &values).await?.json().await?)
+    }
+
+    pub async fn delete_batch(ids: &[RecordId]) -> Result<BatchResult<(), ()>, ApiError> {
+        Ok(send_json(Request::delete("/api/metadata_tag_groups/batch"), ids).await?.json().await?)
+    }
+}
+
+/// Routes of the `financial_goals` table.
+pub mod financial_goals {
+    use super::*;
+
+    pub const ADDRESS: &str = "/api/financial_goals";
+
+    pub async fn all() -> Result<Vec<FinancialGoal>, ApiError> {
+        Ok(send(Request::get("/api/financial_goals/all")).await?.json().await?)
+    }
+
+    pub async fn insert(value: &FinancialGoal) -> Result<RecordId, ApiError> {
+        Ok(send_json(Request::post(ADDRESS), value).await?.json().await?)
+    }
+
+    pub async fn update(id: &RecordId, data: &FinancialGoal) -> Result<(), ApiError> {
+        send_json(Request::put(ADDRESS), &WithId { id, data }).await?;
+        Ok(())
+    }
+
+    pub async fn delete(id: &RecordId) -> Result<(), ApiError> {
+        send_json(Request::delete(ADDRESS), id).await?;
+        Ok(())
+    }
+
+    pub async fn insert_batch(values: &[FinancialGoal]) -> Result<BatchResult<RecordId, FinancialGoalError>, ApiError> {
+        Ok(send_json(Request::post("/api/financial_goals/batch"), values).await?.json().await?)
+    }
+
+    pub async fn update_batch(values: &[(RecordId, FinancialGoal)]) -> Result<BatchResult<(), FinancialGoalError>, ApiError> {
+        let values: Vec<WithId<FinancialGoal>> = values.iter().map(|(id, data)| WithId { id, data }).collect();
+        Ok(send_json(Request::put("/api/financial_goals/batch"), &values).await?.json().await?)
+    }
+
+    pub async fn delete_batch(ids: &[RecordId]) -> Result<BatchResult<(), ()>, ApiError> {
+        Ok(send_json(Request::delete("/api/financial_goals/batch"), ids).await?.json().await?)
+    }
+}
+
+/// Routes of the `transactions` table.
+pub mod transactions {
+    use super::*;
+
+    pub const ADDRESS: &str = "/api/transactions";
+
+    pub async fn all() -> Result<Vec<Transaction>, ApiError> {
+        Ok(send(Request::get("/api/transactions/all")).await?.json().await?)
+    }
+
+    pub async fn insert(value: &Transaction) -> Result<RecordId, ApiError> {
+        Ok(send_json(Request::post(ADDRESS), value).await?.json().await?)
+    }
+
+    pub async fn update(id: &RecordId, data: &Transaction) -> Result<(), ApiError> {
+        send_json(Request::put(ADDRESS), &WithId { id, data }).await?;
+        Ok(())
+    }
+
+    pub async fn delete(id: &RecordId) -> Result<(), ApiError> {
+        send_json(Request::delete(ADDRESS), id).await?;
+        Ok(())
+    }
+
+    pub async fn insert_batch(values: &[Transaction]) -> Result<BatchResult<RecordId, TransactionError>, ApiError> {
+        Ok(send_json(Request::post("/api/transactions/batch"), values).await?.json().await?)
+    }
+
+    pub async fn update_batch(values: &[(RecordId, Transaction)]) -> Result<BatchResult<(), TransactionError>, ApiError> {
+        let values: Vec<WithId<Transaction>> = values.iter().map(|(id, data)| WithId { id, data }).collect();
+        Ok(send_json(Request::put("/api/transactions/batch"), &values).await?.json().await?)
+    }
+
+    pub async fn delete_batch(ids: &[RecordId]) -> Result<BatchResult<(), ()>, ApiError> {
+        Ok(send_json(Request::delete("/api/transactions/batch"), ids).await?.json().await?)
+    }
+
+    /// The changes of the record, oldest first.
+    pub async fn history(id: &str) -> Result<Vec<serde_json::Value>, ApiError> {
+        Ok(send(Request::get("/api/transactions/history").query([("id", id)])).await?.json().await?)
+    }
+
+    pub async fn monthly_amount_by_account() -> Result<Vec<serde_json::Value>, ApiError> {
+        Ok(send(Request::get("/api/transactions/aggregate/monthly_amount_by_account")).await?.json().await?)
+    }
+}
+
+/// Routes of the `transfers` table.
+pub mod transfers {
+    use super::*;
+
+    pub const ADDRESS: &str = "/api/transfers";
+
+    pub async fn all() -> Result<Vec<Transfer>, ApiError> {
+        Ok(send(Request::get("/api/transfers/all")).await?.json().await?)
+    }
+
+    pub async fn insert(value: &Transfer) -> Result<RecordId, ApiError> {
+        Ok(send_json(Request::post(ADDRESS), value).await?.json().await?)
+    }
+
+    pub async fn update(id: &RecordId, data: &Transfer) -> Result<(), ApiError> {
+        send_json(Request::put(ADDRESS), &WithId { id, data }).await?;
+        Ok(())
+    }
+
+    pub async fn delete(id: &RecordId) -> Result<(), ApiError> {
+        send_json(Request::delete(ADDRESS), id).await?;
+        Ok(())
+    }
+
+    pub async fn insert_batch(values: &[Transfer]) -> Result<BatchResult<RecordId, TransferError>, ApiError> {
+        Ok(send_json(Request::post("/api/transfers/batch"), values).await?.json().await?)
+    }
+
+    pub async fn update_batch(values: &[(RecordId, Transfer)]) -> Result<BatchResult<(), TransferError>, ApiError> {
+        let values: Vec<WithId<Transfer>> = values.iter().map(|(id, data)| WithId { id, data }).collect();
+        Ok(send_json(Request::put("/api/transfers/batch"), &values).await?.json().await?)
+    }
+
+    pub async fn delete_batch(ids: &[RecordId]) -> Result<BatchResult<(), ()>, ApiError> {
+        Ok(send_json(Request::delete("/api/transfers/batch"), ids).await?.json().await?)
+    }
+
+    /// The changes of the record, oldest first.
+    pub async fn history(id: &str) -> Result<Vec<serde_json::Value>, ApiError> {
+        Ok(send(Request::get("/api/transfers/history").query([("id", id)])).await?.json().await?)
+    }
+}
+
+/// Routes of the `stable_incomes` table.
+pub mod stable_incomes {
+    use super::*;
+
+    pub const ADDRESS: &str = "/api/stable_incomes";
+
+    pub async fn all() -> Result<Vec<StableIncome>, ApiError> {
+        Ok(send(Request::get("/api/stable_incomes/all")).await?.json().await?)
+    }
+
+    pub async fn insert(value: &StableIncome) -> Result<RecordId, ApiError> {
+        Ok(send_json(Request::post(ADDRESS), value).await?.json().await?)
+    }
+
+    pub async fn update(id: &RecordId, data: &StableIncome) -> Result<(), ApiError> {
+        send_json(Request::put(ADDRESS), &WithId { id, data }).await?;
+        Ok(())
+    }
+
+    pub async fn delete(id: &RecordId) -> Result<(), ApiError> {
+        send_json(Request::delete(ADDRESS), id).await?;
+        Ok(())
+    }
+
+    pub async fn insert_batch(values: &[StableIncome]) -> Result<BatchResult<RecordId, StableIncomeError>, ApiError> {
+        Ok(send_json(Request::post("/api/stable_incomes/batch"), values).await?.json().await?)
+    }
+
+    pub async fn update_batch(values: &[(RecordId, StableIncome)]) -> Result<BatchResult<(), StableIncomeError>, ApiError> {
+        let values: Vec<WithId<StableIncome>> = values.iter().map(|(id, data)| WithId { id, data }).collect();
+        Ok(send_json(Request::put("/api/stable_incomes/batch"), &values).await?.json().await?)
+    }
+
+    pub async fn delete_batch(ids: &[RecordId]) -> Result<BatchResult<(), ()>, ApiError> {
+        Ok(send_json(Request::delete("/api/stable_incomes/batch"), ids).await?.json().await?)
+    }
+}
+
+/// Routes of the `stable_income_incomes` table.
+pub mod stable_income_incomes {
+    use super::*;
+
+    pub const ADDRESS: &str = "/api/stable_income_incomes";
+
+    pub async fn all() -> Result<Vec<StableIncomeIncome>, ApiError> {
+        Ok(send(Request::get("/api/stable_income_incomes/all")).await?.json().await?)
+    }
+
+    pub async fn insert(value: &StableIncomeIncome) -> Result<RecordId, ApiError> {
+        Ok(send_json(Request::post(ADDRESS), value).await?.json().await?)
+    }
+
+    pub async fn update(id: &RecordId, data: &StableIncomeIncome) -> Result<(), ApiError> {
+        send_json(Request::put(ADDRESS), &WithId { id, data }).await?;
+        Ok(())
+    }
+
+    pub async fn delete(id: &RecordId) -> Result<(), ApiError> {
+        send_json(Request::delete(ADDRESS), id).await?;
+        Ok(())
+    }
+
+    pub async fn insert_batch(values: &[StableIncomeIncome]) -> Result<BatchResult<RecordId, StableIncomeIncomeError>, ApiError> {
+        Ok(send_json(Request::post("/api/stable_income_incomes/batch"), values).await?.json().await?)
+    }
+
+    pub async fn update_batch(values: &[(RecordId, StableIncomeIncome)]) -> Result<BatchResult<(), StableIncomeIncomeError>, ApiError> {
+        let values: Vec<WithId<StableIncomeIncome>> = values.iter().map(|(id, data)| WithId { id, data }).collect();
+        Ok(send_json(Request::put("/api/stable_income_incomes/batch"), &values).await?.json().await?)
+    }
+
+    pub async fn delete_batch(ids: &[RecordId]) -> Result<BatchResult<(), ()>, ApiError> {
+        Ok(send_json(Request::delete("/api/stable_income_incomes/batch"), ids).await?.json().await?)
+    }
+}
+
+/// Routes of the `loans` table.
+pub mod loans {
+    use super::*;
+
+    pub const ADDRESS: &str = "/api/loans";
+
+    pub async fn all() -> Result<Vec<Loan>, ApiError> {
+        Ok(send(Request::get("/api/loans/all")).await?.json().await?)
+    }
+
+    pub async fn insert(value: &Loan) -> Result<RecordId, ApiError> {
+        Ok(send_json(Request::post(ADDRESS), value).await?.json().await?)
+    }
+
+    pub async fn update(id: &RecordId, data: &Loan) -> Result<(), ApiError> {
+        send_json(Request::put(ADDRESS), &WithId { id, data }).await?;
+        Ok(())
+    }
+
+    pub async fn delete(id: &RecordId) -> Result<(), ApiError> {
+        send_json(Request::delete(ADDRESS), id).await?;
+        Ok(())
+    }
+
+    pub async fn insert_batch(values: &[Loan]) -> Result<BatchResult<RecordId, LoanError>, ApiError> {
+        Ok(send_json(Request::post("/api/loans/batch"), values).await?.json().await?)
+    }
+
+    pub async fn update_batch(values: &[(RecordId, Loan)]) -> Result<BatchResult<(), LoanError>, ApiError> {
+        let values: Vec<WithId<Loan>> = values.iter().map(|(id, data)| WithId { id, data }).collect();
+        Ok(send_json(Request::put("/api/loans/batch"), &values).await?.json().await?)
+    }
+
+    pub async fn delete_batch(ids: &[RecordId]) -> Result<BatchResult<(), ()>, ApiError> {
+        Ok(send_json(Request::delete("/api/loans/batch"), ids).await?.json().await?)
+    }
+
+    pub async fn restore(id: &RecordId) -> Result<(), ApiError> {
+        send_json(Request::post("/api/loans/restore"), id).await?;
+        Ok(())
+    }
+
+    pub async fn trash() -> Result<Vec<Loan>, ApiError> {
+        Ok(send(Request::get("/api/loans/trash")).await?.json().await?)
+    }
+
+    /// The changes of the record, oldest first.
+    pub async fn history(id: &str) -> Result<Vec<serde_json::Value>, ApiError> {
+        Ok(send(Request::get("/api/loans/history").query([("id", id)])).await?.json().await?)
+    }
+}
+
+/// Routes of the `loan_payments` table.
+pub mod loan_payments {
+    use super::*;
+
+    pub const ADDRESS: &str = "/api/loan_payments";
+
+    pub async fn all() -> Result<Vec<LoanPayment>, ApiError> {
+        Ok(send(Request::get("/api/loan_payments/all")).await?.json().await?)
+    }
+
+    pub async fn insert(value: &LoanPayment) -> Result<RecordId, ApiError> {
+        Ok(send_json(Request::post(ADDRESS), value).await?.json().await?)
+    }
+
+    pub async fn update(id: &RecordId, data: &LoanPayment) -> Result<(), ApiError> {
+        send_json(Request::put(ADDRESS), &WithId { id, data }).await?;
+        Ok(())
+    }
+
+    pub async fn delete(id: &RecordId) -> Result<(), ApiError> {
+        send_json(Request::delete(ADDRESS), id).await?;
+        Ok(())
+    }
+
+    pub async fn insert_batch(values: &[LoanPayment]) -> Result<BatchResult<RecordId, LoanPaymentError>, ApiError> {
+        Ok(send_json(Request::post("/api/loan_payments/batch"), values).await?.json().await?)
+    }
+
+    pub async fn update_batch(values: &[(RecordId, LoanPayment)]) -> Result<BatchResult<(), LoanPaymentError>, ApiError> {
+        let values: Vec<WithId<LoanPayment>> = values.iter().map(|(id, data)| WithId { id, data }).collect();
+        Ok(send_json(Request::put("/api/loan_payments/batch"), &values).await?.json().await?)
+    }
+
+    pub async fn delete_batch(ids: &[RecordId]) -> Result<BatchResult<(), ()>, ApiError> {
+        Ok(send_json(Request::delete("/api/loan_payments/batch"), ids).await?.json().await?)
+    }
+}
+
+/// Routes of the `investments` table.
+pub mod investments {
+    use super::*;
+
+    pub const ADDRESS: &str = "/api/investments";
+
+    pub async fn all() -> Result<Vec<Investment>, ApiError> {
+        Ok(send(Request::get("/api/investments/all")).await?.json().await?)
+    }
+
+    pub async fn insert(value: &Investment) -> Result<RecordId, ApiError> {
+        Ok(send_json(Request::post(ADDRESS), value).await?.json().await?)
+    }
+
+    pub async fn update(id: &RecordId, data: &Investment) -> Result<(), ApiError> {
+        send_json(Request::put(ADDRESS), &WithId { id, data }).await?;
+        Ok(())
+    }
+
+    pub async fn delete(id: &RecordId) -> Result<(), ApiError> {
+        send_json(Request::delete(ADDRESS), id).await?;
+        Ok(())
+    }
+
+    pub async fn insert_batch(values: &[Investment]) -> Result<BatchResult<RecordId, InvestmentError>, ApiError> {
+        Ok(send_json(Request::post("/api/investments/batch"), values).await?.json().await?)
+    }
+
+    pub async fn update_batch(values: &[(RecordId, Investment)]) -> Result<BatchResult<(), InvestmentError>, ApiError> {
+        let values: Vec<WithId<Investment>> = values.iter().map(|(id, data)| WithId { id, data }).collect();
+        Ok(send_json(Request::put("/api/investments/batch"), &values).await?.json().await?)
+    }
+
+    pub async fn delete_batch(ids: &[RecordId]) -> Result<BatchResult<(), ()>, ApiError> {
+        Ok(send_json(Request::delete("/api/investments/batch"), ids).await?.json().await?)
+    }
+}
+
+/// Routes of the `investment_returns` table.
+pub mod investment_returns {
+    use super::*;
+
+    pub const ADDRESS: &str = "/api/investment_returns";
+
+    pub async fn all() -> Result<Vec<InvestmentReturn>, ApiError> {
+        Ok(send(Request::get("/api/investment_returns/all")).await?.json().await?)
+    }
+
+    pub async fn insert(value: &InvestmentReturn) -> Result<RecordId, ApiError> {
+        Ok(send_json(Request::post(ADDRESS), value).await?.json().await?)
+    }
+
+    pub async fn update(id: &RecordId, data: &InvestmentReturn) -> Result<(), ApiError> {
+        send_json(Request::put(ADDRESS), &WithId { id, data }).await?;
+        Ok(())
+    }
+
+    pub async fn delete(id: &RecordId) -> Result<(), ApiError> {
+        send_json(Request::delete(ADDRESS), id).await?;
+        Ok(())
+    }
+
+    pub async fn insert_batch(values: &[InvestmentReturn]) -> Result<BatchResult<RecordId, InvestmentReturnError>, ApiError> {
+        Ok(send_json(Request::post("/api/investment_returns/batch"), values).await?.json().await?)
+    }
+
+    pub async fn update_batch(values: &[(RecordId, InvestmentReturn)]) -> Result<BatchResult<(), InvestmentReturnError>, ApiError> {
+        let values: Vec<WithId<InvestmentReturn>> = values.iter().map(|(id, data)| WithId { id, data }).collect();
+        Ok(send_json(Request::put("/api/investment_returns/batch"), &values).await?.json().await?)
+    }
+
+    pub async fn delete_batch(ids: &[RecordId]) -> Result<BatchResult<(), ()>, ApiError> {
+        Ok(send_json(Request::delete("/api/investment_returns/batch"), ids).await?.json().await?)
+    }
+}
+
+/// Routes of the `metadata` table.
+pub mod metadata {
+    use super::*;
+
+    pub const ADDRESS: &str = "/api/metadata";
+
+    pub async fn all() -> Result<Vec<Metadata>, ApiError> {
+        Ok(send(Request::get("/api/metadata/all")).await?.json().await?)
+    }
+
+    pub async fn insert(value: &Metadata) -> Result<RecordId, ApiError> {
+        Ok(send_json(Request::post(ADDRESS), value).await?.json().await?)
+    }
+
+    pub async fn update(id: &RecordId, data: &Metadata) -> Result<(), ApiError> {
+        send_json(Request::put(ADDRESS), &WithId { id, data }).await?;
+        Ok(())
+    }
+
+    pub async fn delete(id: &RecordId) -> Result<(), ApiError> {
+        send_json(Request::delete(ADDRESS), id).await?;
+        Ok(())
+    }
+
+    pub async fn insert_batch(values: &[Metadata]) -> Result<BatchResult<RecordId, MetadataError>, ApiError> {
+        Ok(send_json(Request::post("/api/metadata/batch"), values).await?.json().await?)
+    }
+
+    pub async fn update_batch(values: &[(RecordId, Metadata)]) -> Result<BatchResult<(), MetadataError>, ApiError> {
+        let values: Vec<WithId<Metadata>> = values.iter().map(|(id, data)| WithId { id, data }).collect();
+        Ok(send_json(Request::put("/api/metadata/batch"), &values).await?.json().await?)
+    }
+
+    pub async fn delete_batch(ids: &[RecordId]) -> Result<BatchResult<(), ()>, ApiError> {
+        Ok(send_json(Request::delete("/api/metadata/batch"), ids).await?.json().await?)
+    }
+
+    /// The matching records with their `score`, best matches first.
+    pub async fn search(q: &str) -> Result<Vec<serde_json::Value>, ApiError> {
+        Ok(send(Request::get("/api/metadata/search").query([("q", q)])).await?.json().await?)
+    }
+}
+
+/// Routes of the `preferences` table.
+pub mod preferences {
+    use super::*;
+
+    pub const ADDRESS: &str = "/api/preferences";
+
+    pub async fn all() -> Result<Vec<Preference>, ApiError> {
+        Ok(send(Request::get("/api/preferences/all")).await?.json().await?)
+    }
+
+    pub async fn insert(value: &Preference) -> Result<RecordId, ApiError> {
+        Ok(send_json(Request::post(ADDRESS), value).await?.json().await?)
+    }
+
+    pub async fn update(id: &RecordId, data: &Preference) -> Result<(), ApiError> {
+        send_json(Request::put(ADDRESS), &WithId { id, data }).await?;
+        Ok(())
+    }
+
+    pub async fn delete(id: &RecordId) -> Result<(), ApiError> {
+        send_json(Request::delete(ADDRESS), id).await?;
+        Ok(())
+    }
+
+    pub async fn insert_batch(values: &[Preference]) -> Result<BatchResult<RecordId, PreferenceError>, ApiError> {
+        Ok(send_json(Request::post("/api/preferences/batch"), values).await?.json().await?)
+    }
+
+    pub async fn update_batch(values: &[(RecordId, Preference)]) -> Result<BatchResult<(), PreferenceError>, ApiError> {
+        let values: Vec<WithId<Preference>> = values.iter().map(|(id, data)| WithId { id, data }).collect();
+        Ok(send_json(Request::put("/api/preferences/batch"), &values).await?.json().await?)
+    }
+
+    pub async fn delete_batch(ids: &[RecordId]) -> Result<BatchResult<(), ()>, ApiError> {
+        Ok(send_json(Request::delete("/api/preferences/batch"), ids).await?.json().await?)
+    }
+}
+
+/// Routes of the `auto_distributions` table.
+pub mod auto_distributions {
+    use super::*;
+
+    pub const ADDRESS: &str = "/api/auto_distributions";
+
+    pub async fn all() -> Result<Vec<AutoDistribution>, ApiError> {
+        Ok(send(Request::get("/api/auto_distributions/all")).await?.json().await?)
+    }
+
+    pub async fn insert(value: &AutoDistribution) -> Result<RecordId, ApiError> {
+        Ok(send_json(Request::post(ADDRESS), value).await?.json().await?)
+    }
+
+    pub async fn update(id: &RecordId, data: &AutoDistribution) -> Result<(), ApiError> {
+        send_json(Request::put(ADDRESS), &WithId { id, data }).await?;
+        Ok(())
+    }
+
+    pub async fn delete(id: &RecordId) -> Result<(), ApiError> {
+        send_json(Request::delete(ADDRESS), id).await?;
+        Ok(())
+    }
+
+    pub async fn insert_batch(values: &[AutoDistribution]) -> Result<BatchResult<RecordId, AutoDistributionError>, ApiError> {
+        Ok(send_json(Request::post("/api/auto_distributions/batch"), values).await?.json().await?)
+    }
+
+    pub async fn update_batch(values: &[(RecordId, AutoDistribution)]) -> Result<BatchResult<(), AutoDistributionError>, ApiError> {
+        let values: Vec<WithId<AutoDistribution>> = values.iter().map(|(id, data)| WithId { id, data }).collect();
+        Ok(send_json(Request::put("/api/auto_distributions/batch"), &values).await?.json().await?)
+    }
+
+    pub async fn delete_batch(ids: &[RecordId]) -> Result<BatchResult<(), ()>, ApiError> {
+        Ok(send_json(Request::delete("/api/auto_distributions/batch"), ids).await?.json().await?)
+    }
+}
+
+/// Routes of the `financial_goal_auto_distributions` table.
+pub mod financial_goal_auto_distributions {
+    use super::*;
+
+    pub const ADDRESS: &str = "/api/financial_goal_auto_distributions";
+
+    pub async fn all() -> Result<Vec<FinancialGoalAutoDistribution>, ApiError> {
+        Ok(send(Request::get("/api/financial_goal_auto_distributions/all")).await?.json().await?)
+    }
+
+    pub async fn insert(value: &FinancialGoalAutoDistribution) -> Result<RecordId, ApiError> {
+        Ok(send_json(Request::post(ADDRESS), value).await?.json().await?)
+    }
+
+    pub async fn update(id: &RecordId, data: &FinancialGoalAutoDistribution) -> Result<(), ApiError> {
+        send_json(Request::put(ADDRESS), &WithId { id, data }).await?;
+        Ok(())
+    }
+
+    pub async fn delete(id: &RecordId) -> Result<(), ApiError> {
+        send_json(Request::delete(ADDRESS), id).await?;
+        Ok(())
+    }
+
+    pub async fn insert_batch(values: &[FinancialGoalAutoDistribution]) -> Result<BatchResult<RecordId, FinancialGoalAutoDistributionError>, ApiError> {
+        Ok(send_json(Request::post("/api/financial_goal_auto_distributions/batch"), values).await?.json().await?)
+    }
+
+    pub async fn update_batch(values: &[(RecordId, FinancialGoalAutoDistribution)]) -> Result<BatchResult<(), FinancialGoalAutoDistributionError>, ApiError> {
+        let values: Vec<WithId<FinancialGoalAutoDistribution>> = values.iter().map(|(id, data)| WithId { id, data }).collect();
+        Ok(send_json(Request::put("/api/financial_goal_auto_distributions/batch"), &values).await?.json().await?)
+    }
+
+    pub async fn delete_batch(ids: &[RecordId]) -> Result<BatchResult<(), ()>, ApiError> {
+        Ok(send_json(Request::delete("/api/financial_goal_auto_distributions/batch"), ids).await?.json().await?)
+    }
+}
+
+/// Routes of the `stable_income_auto_distributions` table.
+pub mod stable_income_auto_distributions {
+    use super::*;
+
+    pub const ADDRESS: &str = "/api/stable_income_auto_distributions";
+
+    pub async fn all() -> Result<Vec<StableIncomeAutoDistribution>, ApiError> {
+        Ok(send(Request::get("/api/stable_income_auto_distributions/all")).await?.json().await?)
+    }
+
+    pub async fn insert(value: &StableIncomeAutoDistribution) -> Result<RecordId, ApiError> {
+        Ok(send_json(Request::post(ADDRESS), value).await?.json().await?)
+    }
+
+    pub async fn update(id: &RecordId, data: &StableIncomeAutoDistribution) -> Result<(), ApiError> {
+        send_json(Request::put(ADDRESS), &WithId { id, data }).await?;
+        Ok(())
+    }
+
+    pub async fn delete(id: &RecordId) -> Result<(), ApiError> {
+        send_json(Request::delete(ADDRESS), id).await?;
+        Ok(())
+    }
+
+    pub async fn insert_batch(values: &[StableIncomeAutoDistribution]) -> Result<BatchResult<RecordId, StableIncomeAutoDistributionError>, ApiError> {
+        Ok(send_json(Request::post("/api/stable_income_auto_distributions/batch"), values).await?.json().await?)
+    }
+
+    pub async fn update_batch(values: &[(RecordId, StableIncomeAutoDistribution)]) -> Result<BatchResult<(), StableIncomeAutoDistributionError>, ApiError> {
+        let values: Vec<WithId<StableIncomeAutoDistribution>> = values.iter().map(|(id, data)| WithId { id, data }).collect();
+        Ok(send_json(Request::put("/api/stable_income_auto_distributions/batch"), &values).await?.json().await?)
+    }
+
+    pub async fn delete_batch(ids: &[RecordId]) -> Result<BatchResult<(), ()>, ApiError> {
+        Ok(send_json(Request::delete("/api/stable_income_auto_distributions/batch"), ids).await?.json().await?)
+    }
+}
+
+/// Routes of the `loan_auto_distributions` table.
+pub mod loan_auto_distributions {
+    use super::*;
+
+    pub const ADDRESS: &str = "/api/loan_auto_distributions";
+
+    pub async fn all() -> Result<Vec<LoanAutoDistribution>, ApiError> {
+        Ok(send(Request::get("/api/loan_auto_distributions/all")).await?.json().await?)
+    }
+
+    pub async fn insert(value: &LoanAutoDistribution) -> Result<RecordId, ApiError> {
+        Ok(send_json(Request::post(ADDRESS), value).await?.json().await?)
+    }
+
+    pub async fn update(id: &RecordId, data: &LoanAutoDistribution) -> Result<(), ApiError> {
+        send_json(Request::put(ADDRESS), &WithId { id, data }).await?;
+        Ok(())
+    }
+
+    pub async fn delete(id: &RecordId) -> Result<(), ApiError> {
+        send_json(Request::delete(ADDRESS), id).await?;
+        Ok(())
+    }
+
+    pub async fn insert_batch(values: &[LoanAutoDistribution]) -> Result<BatchResult<RecordId, LoanAutoDistributionError>, ApiError> {
+        Ok(send_json(Request::post("/api/loan_auto_distributions/batch"), values).await?.json().await?)
+    }
+
+    pub async fn update_batch(values: &[(RecordId, LoanAutoDistribution)]) -> Result<BatchResult<(), LoanAutoDistributionError>, ApiError> {
+        let values: Vec<WithId<LoanAutoDistribution>> = values.iter().map(|(id, data)| WithId { id, data }).collect();
+        Ok(send_json(Request::put("/api/loan_auto_distributions/batch"), &values).await?.json().await?)
+    }
+
+    pub async fn delete_batch(ids: &[RecordId]) -> Result<BatchResult<(), ()>, ApiError> {
+        Ok(send_json(Request::delete("/api/loan_auto_distributions/batch"), ids).await?.json().await?)
+    }
+}
+
+/// Routes of the `investment_auto_distributions` table.
+pub mod investment_auto_distributions {
+    use super::*;
+
+    pub const ADDRESS: &str = "/api/investment_auto_distributions";
+
+    pub async fn all() -> Result<Vec<InvestmentAutoDistribution>, ApiError> {
+        Ok(send(Request::get("/api/investment_auto_distributions/all")).await?.json().await?)
+    }
+
+    pub async fn insert(value: &InvestmentAutoDistribution) -> Result<RecordId, ApiError> {
+        Ok(send_json(Request::post(ADDRESS), value).await?.json().await?)
+    }
+
+    pub async fn update(id: &RecordId, data: &InvestmentAutoDistribution) -> Result<(), ApiError> {
+        send_json(Request::put(ADDRESS), &WithId { id, data }).await?;
+        Ok(())
+    }
+
+    pub async fn delete(id: &RecordId) -> Result<(), ApiError> {
+        send_json(Request::delete(ADDRESS), id).await?;
+        Ok(())
+    }
+
+    pub async fn insert_batch(values: &[InvestmentAutoDistribution]) -> Result<BatchResult<RecordId, InvestmentAutoDistributionError>, ApiError> {
+        Ok(send_json(Request::post("/api/investment_auto_distributions/batch"), values).await?.json().await?)
+    }
+
+    pub async fn update_batch(values: &[(RecordId, InvestmentAutoDistribution)]) -> Result<BatchResult<(), InvestmentAutoDistributionError>, ApiError> {
+        let values: Vec<WithId<InvestmentAutoDistribution>> = values.iter().map(|(id, data)| WithId { id, data }).collect();
+        Ok(send_json(Request::put("/api/investment_auto_distributions/batch"), &values).await?.json().await?)
+    }
+
+    pub async fn delete_batch(ids: &[RecordId]) -> Result<BatchResult<(), ()>, ApiError> {
+        Ok(send_json(Request::delete("/api/investment_auto_distributions/batch"), ids).await?.json().await?)
+    }
+}
+
+/// Routes of the `transfer_auto_distributions` table.
+pub mod transfer_auto_distributions {
+    use super::*;
+
+    pub const ADDRESS: &str = "/api/transfer_auto_distributions";
+
+    pub async fn all() -> Result<Vec<TransferAutoDistribution>, ApiError> {
+        Ok(send(Request::get("/api/transfer_auto_distributions/all")).await?.json().await?)
+    }
+
+    pub async fn insert(value: &TransferAutoDistribution) -> Result<RecordId, ApiError> {
+        Ok(send_json(Request::post(ADDRESS), value).await?.json().await?)
+    }
+
+    pub async fn update(id: &RecordId, data: &TransferAutoDistribution) -> Result<(), ApiError> {
+        send_json(Request::put(ADDRESS), &WithId { id, data }).await?;
+        Ok(())
+    }
+
+    pub async fn delete(id: &RecordId) -> Result<(), ApiError> {
+        send_json(Request::delete(ADDRESS), id).await?;
+        Ok(())
+    }
+
+    pub async fn insert_batch(values: &[TransferAutoDistribution]) -> Result<BatchResult<RecordId, TransferAutoDistributionError>, ApiError> {
+        Ok(send_json(Request::post("/api/transfer_auto_distributions/batch"), values).await?.json().await?)
+    }
+
+    pub async fn update_batch(values: &[(RecordId, TransferAutoDistribution)]) -> Result<BatchResult<(), TransferAutoDistributionError>, ApiError> {
+        let values: Vec<WithId<TransferAutoDistribution>> = values.iter().map(|(id, data)| WithId { id, data }).collect();
+        Ok(send_json(Request::put("/api/transfer_auto_distributions/batch"), &values).await?.json().await?)
+    }
+
+    pub async fn delete_batch(ids: &[RecordId]) -> Result<BatchResult<(), ()>, ApiError> {
+        Ok(send_json(Request::delete("/api/transfer_auto_distributions/batch"), ids).await?.json().await?)
+    }
+}
+
+/// Routes of the `transaction_auto_distributions` table.
+pub mod transaction_auto_distributions {
+    use super::*;
+
+    pub const ADDRESS: &str = "/api/transaction_auto_distributions";
+
+    pub async fn all() -> Result<Vec<TransactionAutoDistribution>, ApiError> {
+        Ok(send(Request::get("/api/transaction_auto_distributions/all")).await?.json().await?)
+    }
+
+    pub async fn insert(value: &TransactionAutoDistribution) -> Result<RecordId, ApiError> {
+        Ok(send_json(Request::post(ADDRESS), value).await?.json().await?)
+    }
+
+    pub async fn update(id: &RecordId, data: &TransactionAutoDistribution) -> Result<(), ApiError> {
+        send_json(Request::put(ADDRESS), &WithId { id, data }).await?;
+        Ok(())
+    }
+
+    pub async fn delete(id: &RecordId) -> Result<(), ApiError> {
+        send_json(Request::delete(ADDRESS), id).await?;
+        Ok(())
+    }
+
+    pub async fn insert_batch(values: &[TransactionAutoDistribution]) -> Result<BatchResult<RecordId, TransactionAutoDistributionError>, ApiError> {
+        Ok(send_json(Request::post("/api/transaction_auto_distributions/batch"), values).await?.json().await?)
+    }
+
+    pub async fn update_batch(values: &[(RecordId, TransactionAutoDistribution)]) -> Result<BatchResult<(), TransactionAutoDistributionError>, ApiError> {
+        let values: Vec<WithId<TransactionAutoDistribution>> = values.iter().map(|(id, data)| WithId { id, data }).collect();
+        Ok(send_json(Request::put("/api/transaction_auto_distributions/batch"), &values).await?.json().await?)
+    }
+
+    pub async fn delete_batch(ids: &[RecordId]) -> Result<BatchResult<(), ()>, ApiError> {
+        Ok(send_json(Request::delete("/api/transaction_auto_distributions/batch"), ids).await?.json().await?)
+    }
+}
+
+/// The authentication endpoints. Successful calls set or remove the session cookies.
+pub mod auth {
+    use super::*;
+
+    /// Why a registration was refused.
+    #[derive(Debug, Deserialize)]
+    #[serde(untagged)]
+    pub enum RegisterFailure {
+        Client(ClientError),
+        Invalid(RegisterError),
+    }
+
+    pub async fn login(creds: &Creds) -> Result<Result<(), ClientError>, ApiError> {
+        session_result(send_json(Request::post("/login"), creds).await?).await
+    }
+
+    pub async fn register(data: &Register) -> Result<Result<(), RegisterFailure>, ApiError> {
+        session_result(send_json(Request::post("/register"), data).await?).await
+    }
+
+    pub async fn logout() -> Result<Result<(), ClientError>, ApiError> {
+        session_result(send(Request::post("/logout")).await?).await
+    }
+
+    pub async fn refresh() -> Result<Result<(), ClientError>, ApiError> {
+        session_result(send(Request::post("/refresh")).await?).await
+    }
+
+    /// Data of the user of the session, without the password.
+    pub async fn me() -> Result<Result<serde_json::Value, ClientError>, ApiError> {
+        let value: serde_json::Value = send(Request::get("/me")).await?.json().await?;
+        Ok(match serde_json::from_value::<Result<(), ClientError>>(value.clone()) {
+            Ok(Err(error)) => Err(error),
+            _ => Ok(value),
+        })
+    }
+}
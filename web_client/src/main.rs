@@ -1,6 +1,7 @@
 mod access_handler;
-mod refresh_request;
+mod api;
 mod bindings;
+mod refresh_request;
 
 use crate::access_handler::get_access;
 use gloo_net::http::Request;
@@ -40,6 +41,14 @@ fn main() {
     spawn_local(async {
         get_access().await.unwrap();
     });
+    spawn_local(async {
+        if let Err(e) = api::install_password_policies().await {
+            web_sys::console::warn_1(&JsValue::from_str(&format!(
+                "Passwords fail `validate()` with UnknownPasswordPolicy, only the server checks them: {:?}",
+                e
+            )));
+        }
+    });
     yew::Renderer::<App>::new().render();
 }
 
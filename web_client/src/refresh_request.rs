@@ -1,15 +1,13 @@
 use crate::bindings::{set_location_href, DomInteractionError};
-use actix_surreal_types::ClientResult;
+use crate::api::{self, ApiError};
 use futures::future::LocalBoxFuture;
 use futures::future::Shared;
 use futures::FutureExt;
-use gloo_net::http::Request;
 use std::cell::{OnceCell, RefCell};
 use std::rc::Rc;
 use derive_more::Display;
 use gloo_net::Error;
 use thiserror::Error;
-use web_sys::{RequestCredentials};
 
 #[derive(Debug, Error, Clone, Display)]
 pub enum RefreshError {
@@ -23,6 +21,15 @@ impl From<gloo_net::Error> for RefreshError {
     }
 }
 
+impl From<ApiError> for RefreshError {
+    fn from(value: ApiError) -> Self {
+        match value {
+            ApiError::Net(error) => error.into(),
+            ApiError::Status(status, body) => Self::GlooNet(format!("{}: {}", status, body)),
+        }
+    }
+}
+
 thread_local! {
     pub static TOKEN_REFRESHER: OnceCell<Rc<RefCell<Option<Shared<LocalBoxFuture<'static, Result<(), RefreshError>>>>>>> =
     {
@@ -38,11 +45,7 @@ pub fn refresh_or_relocate() -> Shared<LocalBoxFuture<'static, Result<(), Refres
         match future.as_mut() {
             None => {
                 let task = async move {
-                    Request::post("/refresh")
-                        .credentials(RequestCredentials::Include)
-                        .send()
-                        .await?
-                        .json::<ClientResult>()
+                    api::auth::refresh()
                         .await?
                         .inspect_err(|_| to_auth().unwrap() /* Hack: unwrap */)
                         .ok();